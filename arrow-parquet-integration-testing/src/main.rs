@@ -173,6 +173,7 @@ fn main() -> Result<()> {
         compression: args.compression.into(),
         version: args.version.into(),
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let encodings = schema
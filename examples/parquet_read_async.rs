@@ -32,6 +32,8 @@ async fn main() -> Result<()> {
     };
 
     // This is the row group loop. Groups can be skipped based on the statistics they carry.
+    // `read::FileStream` implements this loop (with projection, limit and page indexes) as a
+    // `Stream` of chunks.
     for row_group in &metadata.row_groups {
         // A row group is consumed in two steps: the first step is to read the (compressed)
         // columns into memory, which is IO-bounded.
@@ -13,6 +13,8 @@ mod ffi;
 pub(super) mod fmt;
 mod iterator;
 pub use iterator::*;
+mod mutable;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
 #[derive(Clone)]
@@ -149,6 +151,16 @@ impl MapArray {
     pub(crate) fn get_field(data_type: &DataType) -> &Field {
         Self::try_get_field(data_type).unwrap()
     }
+
+    /// Returns a default [`DataType`] of unsorted maps: inner field is named "entries" and is
+    /// a struct of non-nullable "keys" and nullable "values".
+    pub fn default_datatype(keys: DataType, values: DataType) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("keys", keys, false),
+            Field::new("values", values, true),
+        ]);
+        DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+    }
 }
 
 // Accessors
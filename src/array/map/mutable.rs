@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::Offsets,
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`], whose keys and values are built by
+/// the [`MutableArray`]s `K` and `V`.
+/// # Example
+/// ```
+/// use arrow2::array::{MapArray, MutableMapArray, MutablePrimitiveArray, MutableUtf8Array, TryExtend};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![
+///     Some(vec![(Some("a"), Some(1)), (Some("b"), None)]),
+///     None,
+///     Some(vec![]),
+/// ])?;
+/// let array: MapArray = array.into();
+///
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.value(0).len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    offsets: Offsets<i32>,
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> MutableMapArray<K, V> {
+    /// Creates a new empty [`MutableMapArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new [`MutableMapArray`] with a capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let keys = K::default();
+        let values = V::default();
+        let data_type =
+            MapArray::default_datatype(keys.data_type().clone(), values.data_type().clone());
+        Self {
+            data_type,
+            offsets: Offsets::with_capacity(capacity),
+            keys,
+            values,
+            validity: None,
+        }
+    }
+}
+
+impl<K: MutableArray + Default, V: MutableArray + Default> Default for MutableMapArray<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Creates a new [`MutableMapArray`] from its [`DataType`] and empty keys and values.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s logical type is not [`DataType::Map`]
+    /// * The inner `Struct` of `data_type` does not have 2 fields whose data types are
+    /// equal to the ones of `keys` and `values`
+    /// * `keys` or `values` are not empty
+    pub fn try_new(data_type: DataType, keys: K, values: V) -> Result<Self> {
+        let field = MapArray::try_get_field(&data_type)?;
+        let inner = if let DataType::Struct(inner) = field.data_type() {
+            inner
+        } else {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects `DataType::Struct` as its inner logical type".to_string(),
+            ));
+        };
+        if inner.len() != 2
+            || inner[0].data_type() != keys.data_type()
+            || inner[1].data_type() != values.data_type()
+        {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects its keys and values to match its inner DataType"
+                    .to_string(),
+            ));
+        }
+        if !keys.is_empty() || !values.is_empty() {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects empty keys and values".to_string(),
+            ));
+        }
+        Ok(Self {
+            data_type,
+            offsets: Offsets::new(),
+            keys,
+            values,
+            validity: None,
+        })
+    }
+
+    /// Needs to be called when valid entries were pushed to the keys and values of this array.
+    /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// Errors iff the keys and values have a different length, any of the keys pushed since
+    /// the last entry is null, or the offsets overflow.
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        if self.keys.len() != self.values.len() {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects its keys and values to have the same length".to_string(),
+            ));
+        }
+        let total_length = self.keys.len();
+        let offset = *self.offsets.last() as usize;
+        let length = total_length.checked_sub(offset).ok_or(Error::Overflow)?;
+        if let Some(validity) = self.keys.validity() {
+            if (offset..total_length).any(|i| !validity.get(i)) {
+                return Err(Error::InvalidArgumentError(
+                    "MutableMapArray expects its keys to be non-null".to_string(),
+                ));
+            }
+        }
+
+        self.offsets.try_push_usize(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &Offsets<i32> {
+        &self.offsets
+    }
+
+    /// The keys
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+
+    /// The mutable keys
+    pub fn mut_keys(&mut self) -> &mut K {
+        &mut self.keys
+    }
+
+    /// The values
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    /// The mutable values
+    pub fn mut_values(&mut self) -> &mut V {
+        &mut self.values
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    fn take_into(&mut self) -> MapArray {
+        let field = MapArray::get_field(&self.data_type).data_type().clone();
+        let field = StructArray::new(field, vec![self.keys.as_box(), self.values.as_box()], None);
+        MapArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            field.boxed(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> From<MutableMapArray<K, V>> for MapArray {
+    fn from(mut other: MutableMapArray<K, V>) -> Self {
+        other.take_into()
+    }
+}
+
+impl<K, V, I, TK, TV> TryPush<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<TK>,
+    V: MutableArray + TryPush<TV>,
+    I: IntoIterator<Item = (TK, TV)>,
+{
+    #[inline]
+    fn try_push(&mut self, item: Option<I>) -> Result<()> {
+        if let Some(items) = item {
+            for (key, value) in items {
+                self.keys.try_push(key)?;
+                self.values.try_push(value)?;
+            }
+            self.try_push_valid()?;
+        } else {
+            self.push_null();
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, I, TK, TV> TryExtend<Option<I>> for MutableMapArray<K, V>
+where
+    K: MutableArray + TryPush<TK>,
+    V: MutableArray + TryPush<TV>,
+    I: IntoIterator<Item = (TK, TV)>,
+{
+    fn try_extend<II: IntoIterator<Item = Option<I>>>(&mut self, iter: II) -> Result<()> {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for items in iter {
+            self.try_push(items)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn len(&self) -> usize {
+        MutableMapArray::len(self)
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_into().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_into().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
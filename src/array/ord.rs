@@ -5,7 +5,10 @@ use std::cmp::Ordering;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::offset::Offset;
-use crate::{array::*, types::NativeType};
+use crate::{
+    array::*,
+    types::{days_ms, f16, i256, months_days_ns, NativeType},
+};
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
@@ -104,6 +107,54 @@ fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
     Box::new(move |i, j| total_cmp_f64(&left.value(i), &right.value(j)))
 }
 
+fn compare_f16(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| total_cmp_f32(&left.value(i).to_f32(), &right.value(j).to_f32()))
+}
+
+fn compare_days_ms(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<days_ms>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<days_ms>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| {
+        let (l, r) = (left.value(i), right.value(j));
+        (l.days(), l.milliseconds()).cmp(&(r.days(), r.milliseconds()))
+    })
+}
+
+fn compare_months_days_ns(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| {
+        let (l, r) = (left.value(i), right.value(j));
+        (l.months(), l.days(), l.ns()).cmp(&(r.months(), r.days(), r.ns()))
+    })
+}
+
 fn compare_string<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparator {
     let left = left
         .as_any()
@@ -211,9 +262,17 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        // timestamps with a timezone are instants since the epoch in UTC
+        (Timestamp(lhs, Some(_)), Timestamp(rhs, Some(_))) if lhs == rhs => {
+            compare_primitives::<i64>(left, right)
+        }
+        (Interval(DayTime), Interval(DayTime)) => compare_days_ms(left, right),
+        (Interval(MonthDayNano), Interval(MonthDayNano)) => compare_months_days_ns(left, right),
+        (Float16, Float16) => compare_f16(left, right),
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
+        (Decimal256(_, _), Decimal256(_, _)) => compare_primitives::<i256>(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
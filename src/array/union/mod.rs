@@ -13,6 +13,8 @@ mod data;
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+mod mutable;
+pub use mutable::*;
 
 type UnionComponents<'a> = (&'a [Field], Option<&'a [i32]>, UnionMode);
 
@@ -349,7 +351,7 @@ impl Array for UnionArray {
 }
 
 impl UnionArray {
-    fn try_get_all(data_type: &DataType) -> Result<UnionComponents, Error> {
+    pub(crate) fn try_get_all(data_type: &DataType) -> Result<UnionComponents, Error> {
         match data_type.to_logical_type() {
             DataType::Union(fields, ids, mode) => {
                 Ok((fields, ids.as_ref().map(|x| x.as_ref()), *mode))
@@ -0,0 +1,325 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+use super::UnionArray;
+
+/// A value that can be pushed to a [`MutableUnionArray`], tagged with the type id
+/// of the field it belongs to.
+/// # Example
+/// ```
+/// use arrow2::array::*;
+/// use arrow2::datatypes::{DataType, Field, UnionMode};
+/// use arrow2::error::Result;
+///
+/// enum Value {
+///     Int(i32),
+///     Str(&'static str),
+/// }
+///
+/// impl TaggedValue for Value {
+///     fn type_id(&self) -> i8 {
+///         match self {
+///             Value::Int(_) => 0,
+///             Value::Str(_) => 1,
+///         }
+///     }
+///
+///     fn try_push_to(self, field: &mut dyn MutableArray) -> Result<()> {
+///         let field = field.as_mut_any();
+///         match self {
+///             Value::Int(v) => field
+///                 .downcast_mut::<MutablePrimitiveArray<i32>>()
+///                 .unwrap()
+///                 .try_push(Some(v)),
+///             Value::Str(v) => field
+///                 .downcast_mut::<MutableUtf8Array<i32>>()
+///                 .unwrap()
+///                 .try_push(Some(v)),
+///         }
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let fields = vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let data_type = DataType::Union(fields, None, UnionMode::Dense);
+/// let mut array = MutableUnionArray::new(
+///     data_type,
+///     vec![
+///         Box::new(MutablePrimitiveArray::<i32>::new()),
+///         Box::new(MutableUtf8Array::<i32>::new()),
+///     ],
+/// );
+/// array.try_extend(vec![Some(Value::Int(1)), Some(Value::Str("a")), None])?;
+///
+/// let array: UnionArray = array.into();
+/// assert_eq!(array.types().as_slice(), &[0, 1, 0]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait TaggedValue {
+    /// The type id of the field of the [`MutableUnionArray`] this value belongs to.
+    fn type_id(&self) -> i8;
+
+    /// Pushes this value to `field`, the field of [`TaggedValue::type_id`].
+    fn try_push_to(self, field: &mut dyn MutableArray) -> Result<()>;
+}
+
+/// The mutable version of [`UnionArray`], both sparse and dense.
+///
+/// Nulls are pushed as nulls of the first field.
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    fields: Vec<Box<dyn MutableArray>>,
+    // `Some` iff the union is dense
+    offsets: Option<Vec<i32>>,
+    // the number of values pushed to each field
+    lengths: Vec<usize>,
+}
+
+impl MutableUnionArray {
+    /// Creates a new [`MutableUnionArray`].
+    /// # Panics
+    /// This function panics iff [`MutableUnionArray::try_new`] errors.
+    pub fn new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, fields).unwrap()
+    }
+
+    /// Creates a new [`MutableUnionArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`]
+    /// * the number of `fields` is different from the `data_type`'s children's length
+    /// * any of the `fields`'s data type is different from its corresponding children' data type
+    /// * any of the `fields` is not empty
+    pub fn try_new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let (f, _, mode) = UnionArray::try_get_all(&data_type)?;
+
+        if f.len() != fields.len() {
+            return Err(Error::oos(
+                "The number of `fields` must equal the number of children fields in DataType::Union",
+            ));
+        };
+        if fields.is_empty() {
+            return Err(Error::oos(
+                "A MutableUnionArray must have at least one field",
+            ));
+        }
+        if f.iter()
+            .zip(fields.iter())
+            .any(|(f, field)| f.data_type() != field.data_type())
+        {
+            return Err(Error::oos(
+                "The children DataTypes of a MutableUnionArray must equal the children data types",
+            ));
+        }
+        if fields.iter().any(|field| !field.is_empty()) {
+            return Err(Error::oos(
+                "The fields of a MutableUnionArray must be empty",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            types: vec![],
+            lengths: vec![0; fields.len()],
+            fields,
+            offsets: (!mode.is_sparse()).then(Vec::new),
+        })
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// The types of this array
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The offsets of this array, `Some` iff the union is dense
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    /// The fields of this array
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    /// The optional mutable field of `type_id`, downcasted to `M`.
+    pub fn value<M: 'static>(&mut self, type_id: i8) -> Option<&mut M> {
+        let index = self.index(type_id).ok()?;
+        self.fields[index].as_mut_any().downcast_mut::<M>()
+    }
+
+    // The index of the field of `type_id`
+    fn index(&self, type_id: i8) -> Result<usize> {
+        let (_, ids, _) = UnionArray::get_all(&self.data_type);
+        let index = match ids {
+            Some(ids) => ids.iter().position(|id| *id == type_id as i32),
+            None => (type_id >= 0).then_some(type_id as usize),
+        };
+        index
+            .filter(|index| *index < self.fields.len())
+            .ok_or_else(|| Error::oos(format!("The type id {type_id} is not part of the union")))
+    }
+
+    /// Needs to be called after a single value was pushed to the field of `type_id`.
+    /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// Errors iff `type_id` is not part of this union or its field did not grow by one value.
+    pub fn try_push_type(&mut self, type_id: i8) -> Result<()> {
+        let index = self.index(type_id)?;
+        let expected = self.lengths[index];
+        if self.fields[index].len() != expected + 1 {
+            return Err(Error::oos(
+                "The field of a MutableUnionArray must grow by one value per pushed type",
+            ));
+        }
+
+        self.types.push(type_id);
+        match &mut self.offsets {
+            Some(offsets) => {
+                offsets.push(expected.try_into().map_err(|_| Error::Overflow)?);
+                self.lengths[index] += 1;
+            }
+            None => {
+                self.fields
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .for_each(|(_, field)| field.push_null());
+                self.lengths.iter_mut().for_each(|x| *x += 1);
+            }
+        }
+        Ok(())
+    }
+
+    fn push_null(&mut self) {
+        let type_id = match UnionArray::get_all(&self.data_type).1 {
+            Some(ids) => ids[0] as i8,
+            None => 0,
+        };
+        self.fields[0].push_null();
+        // the first field has grown by one value
+        self.try_push_type(type_id).unwrap()
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        if let Some(offsets) = self.offsets.as_mut() {
+            offsets.reserve(additional)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        if let Some(offsets) = self.offsets.as_mut() {
+            offsets.shrink_to_fit()
+        }
+        self.fields.iter_mut().for_each(|x| x.shrink_to_fit());
+    }
+
+    fn take_into(&mut self) -> UnionArray {
+        let array = UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            self.fields.iter_mut().map(|x| x.as_box()).collect(),
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        );
+        self.lengths.iter_mut().for_each(|x| *x = 0);
+        array
+    }
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(mut other: MutableUnionArray) -> Self {
+        other.take_into()
+    }
+}
+
+impl<T: TaggedValue> TryPush<Option<T>> for MutableUnionArray {
+    fn try_push(&mut self, item: Option<T>) -> Result<()> {
+        match item {
+            Some(value) => {
+                let type_id = value.type_id();
+                let index = self.index(type_id)?;
+                value.try_push_to(self.fields[index].as_mut())?;
+                self.try_push_type(type_id)
+            }
+            None => {
+                self.push_null();
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<T: TaggedValue> TryExtend<Option<T>> for MutableUnionArray {
+    fn try_extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) -> Result<()> {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.try_push(item)?;
+        }
+        Ok(())
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        MutableUnionArray::len(self)
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_into().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_into().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+}
@@ -113,7 +113,7 @@ pub fn div_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> Pr
 /// InvalidArgumentError is returned. If the result from the division is
 /// larger than the possible number with the selected precision then the
 /// resulted number in the arrow array is the maximum number for the selected
-/// precision. The function panics if divided by zero.
+/// precision. A division by zero results in zero.
 ///
 /// # Examples
 /// ```
@@ -53,7 +53,7 @@ fn get_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize)> {
 
 /// Returns the adjusted precision and scale for the lhs and rhs precision and
 /// scale
-fn adjusted_precision_scale(
+pub(super) fn adjusted_precision_scale(
     lhs_p: usize,
     lhs_s: usize,
     rhs_p: usize,
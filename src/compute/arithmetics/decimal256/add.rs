@@ -0,0 +1,201 @@
+//! Defines the addition arithmetic kernels for [`PrimitiveArray`] representing 256-bit decimals.
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayAdd, ArrayCheckedAdd, ArraySaturatingAdd},
+        arity::{binary, binary_checked},
+        utils::{check_same_len, combine_validities},
+    },
+    datatypes::DataType,
+    error::Result,
+    types::i256,
+};
+
+use super::{
+    adapt_precision, adjusted_precision_scale, get_adaptive_parameters, get_parameters, max_value,
+    power_of_ten, MAX_PRECISION,
+};
+
+/// Adds two decimal256 [`PrimitiveArray`] with the same precision and scale.
+/// # Error
+/// Errors if the precision and scale are different.
+/// # Panic
+/// This function panics iff the added numbers result in a number larger than
+/// the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 1)), None]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 2)), None]).to(DataType::Decimal256(5, 2));
+///
+/// let result = add(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 3)), None]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.0 + b.0;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in addition presented for precision {precision}"
+        );
+
+        i256(res)
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Saturated addition of two decimal256 primitive arrays with the same precision
+/// and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the sum is larger than
+/// the possible number with the selected precision then the resulted number in
+/// the arrow array is the maximum number for the selected precision.
+pub fn saturating_add(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.0 + b.0;
+
+        if res.abs() > max {
+            if res > 0 {
+                i256(max)
+            } else {
+                i256(-max)
+            }
+        } else {
+            i256(res)
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked addition of two decimal256 primitive arrays with the same precision
+/// and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the sum is larger than
+/// the possible number with the selected precision (overflowing), then the
+/// validity for that index is changed to None
+pub fn checked_add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let result = a.0 + b.0;
+
+        if result.abs() > max {
+            None
+        } else {
+            Some(i256(result))
+        }
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArrayAdd trait for PrimitiveArrays
+impl ArrayAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        add(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedAdd trait for PrimitiveArrays
+impl ArrayCheckedAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_add(self, rhs)
+    }
+}
+
+// Implementation of ArraySaturatingAdd trait for PrimitiveArrays
+impl ArraySaturatingAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_add(self, rhs)
+    }
+}
+
+/// Adaptive addition of two decimal256 primitive arrays with different precision
+/// and scale. If the precision and scale is different, then the smallest scale
+/// and precision is adjusted to the largest precision and scale. If during the
+/// addition one of the results is larger than the max possible value, the
+/// result precision is changed to the precision of the max value
+///
+/// ```nocode
+/// 11111.11   -> 7, 2
+/// 11111.111  -> 8, 3
+/// ------------------
+/// 22222.221  -> 8, 3
+/// ```
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::adaptive_add;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 11111_11))]).to(DataType::Decimal256(7, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 11111_111))]).to(DataType::Decimal256(8, 3));
+/// let result = adaptive_add(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 22222_221))]).to(DataType::Decimal256(8, 3));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn adaptive_add(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    check_same_len(lhs, rhs)?;
+
+    let (lhs_p, lhs_s, rhs_p, rhs_s) = get_adaptive_parameters(lhs.data_type(), rhs.data_type())?;
+
+    // The resulting precision is mutable because it could change while
+    // looping through the iterator
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let mut res_p = res_p.min(MAX_PRECISION);
+
+    let shift = power_of_ten(diff);
+    let mut max = max_value(res_p);
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(l, r)| {
+            // Based on the array's scales one of the arguments in the sum has to be shifted
+            // to the left to match the final scale
+            let res = if lhs_s > rhs_s {
+                l.0 + r.0 * shift
+            } else {
+                l.0 * shift + r.0
+            };
+
+            // The precision of the resulting array will change if one of the
+            // sums during the iteration produces a value bigger than the
+            // possible value for the initial precision
+            adapt_precision(res, &mut res_p, &mut max)?;
+            Ok(i256(res))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(res_p, res_s),
+        values.into(),
+        validity,
+    ))
+}
@@ -0,0 +1,277 @@
+//! Defines the division arithmetic kernels for Decimal256
+//! `PrimitiveArrays`.
+
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayCheckedDiv, ArrayDiv},
+        arity::{binary, binary_checked, unary},
+        utils::{check_same_len, combine_validities},
+    },
+    datatypes::DataType,
+    error::Result,
+    scalar::{PrimitiveScalar, Scalar},
+    types::i256,
+};
+
+use super::{
+    adapt_precision, adjusted_precision_scale, get_adaptive_parameters, get_parameters, max_value,
+    power_of_ten, MAX_PRECISION,
+};
+
+/// Divide two decimal256 primitive arrays with the same precision and scale. If
+/// the precision and scale is different, then an InvalidArgumentError is
+/// returned. This function panics if the dividend is divided by 0 or None.
+/// This function also panics if the division produces a number larger
+/// than the possible number for the array precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::div;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 6_00))]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 2_00))]).to(DataType::Decimal256(5, 2));
+///
+/// let result = div(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 3_00))]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn div(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        // The division is done using the numbers without scale.
+        // The dividend is scaled up to maintain precision after the
+        // division
+        let numeral = a.0.checked_mul(scale).expect("Mayor overflow for division");
+
+        // The division can overflow if the dividend is divided
+        // by zero.
+        let res = numeral.checked_div(b.0).expect("Found division by zero");
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in division presented for precision {precision}"
+        );
+
+        i256(res)
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Divide a decimal256 [`PrimitiveArray`] with a [`PrimitiveScalar`] with the same precision and scale. If
+/// the precision and scale is different, then an InvalidArgumentError is
+/// returned. This function panics if the divisor is zero or if the division
+/// results in a number larger than the possible number for the selected precision.
+pub fn div_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = *rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i256>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256| {
+        let numeral = a.0.checked_mul(scale).expect("Mayor overflow for division");
+
+        let res = numeral.checked_div(rhs.0).expect("Found division by zero");
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in division presented for precision {precision}"
+        );
+
+        i256(res)
+    };
+
+    unary(lhs, op, lhs.data_type().clone())
+}
+
+/// Saturated division of two decimal256 primitive arrays with the same
+/// precision and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the division is
+/// larger than the possible number with the selected precision then the
+/// resulted number in the arrow array is the maximum number for the selected
+/// precision. A division by zero results in zero.
+pub fn saturating_div(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        let numeral = match a.0.checked_mul(scale) {
+            Some(numeral) => numeral,
+            None => {
+                return if (a.0 > 0) == (b.0 > 0) {
+                    i256(max)
+                } else {
+                    i256(-max)
+                }
+            }
+        };
+
+        match numeral.checked_div(b.0) {
+            Some(res) => match res {
+                res if res.abs() > max => {
+                    if res > 0 {
+                        i256(max)
+                    } else {
+                        i256(-max)
+                    }
+                }
+                _ => i256(res),
+            },
+            None => i256::default(),
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked division of two decimal256 primitive arrays with the same precision
+/// and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the divisor is zero, then the
+/// validity for that index is changed to None
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::checked_div;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 1_00)), Some(i256::from_words(0, 6_00))]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 0)), Some(i256::from_words(0, 2_00))]).to(DataType::Decimal256(5, 2));
+///
+/// let result = checked_div(&a, &b);
+/// let expected = PrimitiveArray::from([None, Some(i256::from_words(0, 3_00))]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_div(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        let numeral = a.0.checked_mul(scale)?;
+
+        match numeral.checked_div(b.0) {
+            Some(res) => match res {
+                res if res.abs() > max => None,
+                _ => Some(i256(res)),
+            },
+            None => None,
+        }
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArrayDiv trait for PrimitiveArrays
+impl ArrayDiv<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn div(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        div(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedDiv trait for PrimitiveArrays
+impl ArrayCheckedDiv<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_div(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_div(self, rhs)
+    }
+}
+
+/// Adaptive division of two decimal256 primitive arrays with different precision
+/// and scale. If the precision and scale is different, then the smallest scale
+/// and precision is adjusted to the largest precision and scale. If during the
+/// division one of the results is larger than the max possible value, the
+/// result precision is changed to the precision of the max value. The function
+/// panics when divided by zero.
+///
+/// ```nocode
+///  1000.00   -> 7, 2
+///    10.0000 -> 6, 4
+/// -----------------
+///   100.0000 -> 9, 4
+/// ```
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::adaptive_div;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 1000_00))]).to(DataType::Decimal256(7, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 10_0000))]).to(DataType::Decimal256(6, 4));
+/// let result = adaptive_div(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 100_0000))]).to(DataType::Decimal256(9, 4));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn adaptive_div(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    check_same_len(lhs, rhs)?;
+
+    let (lhs_p, lhs_s, rhs_p, rhs_s) = get_adaptive_parameters(lhs.data_type(), rhs.data_type())?;
+
+    // The resulting precision is mutable because it could change while
+    // looping through the iterator
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let mut res_p = res_p.min(MAX_PRECISION);
+
+    let shift = power_of_ten(diff);
+    let shift_1 = power_of_ten(res_s);
+    let mut max = max_value(res_p);
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(l, r)| {
+            let numeral = l.0 * shift_1;
+
+            // Based on the array's scales one of the arguments in the sum has to be shifted
+            // to the left to match the final scale
+            let res = if lhs_s > rhs_s {
+                numeral.checked_div(r.0 * shift)
+            } else {
+                (numeral * shift).checked_div(r.0)
+            }
+            .expect("Found division by zero");
+
+            // The precision of the resulting array will change if one of the
+            // divisions during the iteration produces a value bigger
+            // than the possible value for the initial precision
+            adapt_precision(res, &mut res_p, &mut max)?;
+
+            Ok(i256(res))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(res_p, res_s),
+        values.into(),
+        validity,
+    ))
+}
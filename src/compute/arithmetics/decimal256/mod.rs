@@ -0,0 +1,208 @@
+//! Defines the arithmetic kernels for Decimal256 `PrimitiveArrays`. The
+//! [`Decimal256`](crate::datatypes::DataType::Decimal256) type specifies the
+//! precision and scale parameters. These affect the arithmetic operations and
+//! need to be considered while doing operations with Decimal256 numbers.
+//!
+//! Arrays of [`Decimal`](crate::datatypes::DataType::Decimal) can be combined with
+//! arrays of [`Decimal256`](crate::datatypes::DataType::Decimal256) by first promoting
+//! them via [`promote`], and then to a common precision and scale via [`promote_to_common`].
+
+mod add;
+pub use add::*;
+mod div;
+pub use div::*;
+mod mul;
+pub use mul::*;
+mod sub;
+pub use sub::*;
+
+use ethnum::I256;
+
+use crate::array::PrimitiveArray;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+use crate::types::i256;
+
+use super::decimal::adjusted_precision_scale;
+
+/// The maximum precision of a [`Decimal256`](DataType::Decimal256)
+const MAX_PRECISION: usize = 76;
+
+/// Maximum value that can exist with a selected precision
+#[inline]
+fn max_value(precision: usize) -> I256 {
+    I256::new(10).pow(precision as u32) - 1
+}
+
+/// Returns `10^exp`
+#[inline]
+fn power_of_ten(exp: usize) -> I256 {
+    I256::new(10).pow(exp as u32)
+}
+
+// Calculates the number of digits in a i256 number
+fn number_digits(num: I256) -> usize {
+    let mut num = num.abs();
+    let mut digit: usize = 0;
+    let base = I256::new(10);
+
+    while num != 0 {
+        num /= base;
+        digit += 1;
+    }
+
+    digit
+}
+
+/// Widens `precision` (and its maximum value, `max`) when `value` does not fit in it.
+/// # Error
+/// Errors iff `value` has more than [`MAX_PRECISION`] digits.
+fn adapt_precision(value: I256, precision: &mut usize, max: &mut I256) -> Result<()> {
+    if value.abs() > *max {
+        let digits = number_digits(value);
+        if digits > MAX_PRECISION {
+            return Err(Error::InvalidArgumentError(format!(
+                "The result does not fit in a Decimal256 of precision {MAX_PRECISION}"
+            )));
+        }
+        *precision = digits;
+        *max = max_value(digits);
+    }
+    Ok(())
+}
+
+fn get_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize)> {
+    if let (DataType::Decimal256(lhs_p, lhs_s), DataType::Decimal256(rhs_p, rhs_s)) =
+        (lhs.to_logical_type(), rhs.to_logical_type())
+    {
+        if lhs_p == rhs_p && lhs_s == rhs_s {
+            Ok((*lhs_p, *lhs_s))
+        } else {
+            Err(Error::InvalidArgumentError(
+                "Arrays must have the same precision and scale".to_string(),
+            ))
+        }
+    } else {
+        unreachable!()
+    }
+}
+
+fn get_adaptive_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize, usize, usize)> {
+    if let (DataType::Decimal256(lhs_p, lhs_s), DataType::Decimal256(rhs_p, rhs_s)) =
+        (lhs.to_logical_type(), rhs.to_logical_type())
+    {
+        Ok((*lhs_p, *lhs_s, *rhs_p, *rhs_s))
+    } else {
+        Err(Error::InvalidArgumentError(
+            "Incorrect data type for the array".to_string(),
+        ))
+    }
+}
+
+/// Promotes a [`Decimal`](DataType::Decimal) array to a
+/// [`Decimal256`](DataType::Decimal256) array with the same precision and scale.
+/// # Error
+/// Errors iff `array` is not of logical type [`Decimal`](DataType::Decimal).
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::promote;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(1_00i128), None]).to(DataType::Decimal(5, 2));
+/// let result = promote(&a).unwrap();
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 1_00)), None]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn promote(array: &PrimitiveArray<i128>) -> Result<PrimitiveArray<i256>> {
+    let (precision, scale) = if let DataType::Decimal(p, s) = array.data_type().to_logical_type() {
+        (*p, *s)
+    } else {
+        return Err(Error::InvalidArgumentError(
+            "Only Decimal arrays can be promoted to Decimal256".to_string(),
+        ));
+    };
+
+    let values = array
+        .values()
+        .iter()
+        .map(|x| i256(I256::new(*x)))
+        .collect::<Vec<_>>();
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(precision, scale),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Rescales two [`Decimal256`](DataType::Decimal256) arrays to a common precision and scale,
+/// the largest number of digits before and after the point of the two.
+/// # Error
+/// Errors iff either array is not of logical type [`Decimal256`](DataType::Decimal256) or the
+/// common precision is larger than 76.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::promote_to_common;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 11_1))]).to(DataType::Decimal256(3, 1));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 1_11))]).to(DataType::Decimal256(3, 2));
+/// let (a, b) = promote_to_common(&a, &b).unwrap();
+///
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 11_10))]).to(DataType::Decimal256(4, 2));
+/// assert_eq!(a, expected);
+/// assert_eq!(b.data_type(), &DataType::Decimal256(4, 2));
+/// ```
+pub fn promote_to_common(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<(PrimitiveArray<i256>, PrimitiveArray<i256>)> {
+    let (lhs_p, lhs_s, rhs_p, rhs_s) = get_adaptive_parameters(lhs.data_type(), rhs.data_type())?;
+    let (precision, scale, _) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    if precision > MAX_PRECISION {
+        return Err(Error::InvalidArgumentError(format!(
+            "The common precision of Decimal256({lhs_p}, {lhs_s}) and Decimal256({rhs_p}, {rhs_s}) is larger than {MAX_PRECISION}"
+        )));
+    }
+
+    // values fit in the common precision since neither the digits before nor after the point shrink
+    let rescale = |array: &PrimitiveArray<i256>, array_s: usize| {
+        let shift = power_of_ten(scale - array_s);
+        let values = array
+            .values()
+            .iter()
+            .map(|x| i256(x.0 * shift))
+            .collect::<Vec<_>>();
+        PrimitiveArray::<i256>::new(
+            DataType::Decimal256(precision, scale),
+            values.into(),
+            array.validity().cloned(),
+        )
+    };
+    Ok((rescale(lhs, lhs_s), rescale(rhs, rhs_s)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_value() {
+        assert_eq!(I256::new(999), max_value(3));
+        assert_eq!(I256::new(99999), max_value(5));
+        assert_eq!(I256::new(10).pow(76) - 1, max_value(76));
+    }
+
+    #[test]
+    fn test_number_digits() {
+        assert_eq!(2, number_digits(I256::new(12)));
+        assert_eq!(3, number_digits(I256::new(-123)));
+        assert_eq!(7, number_digits(I256::new(-1234567)));
+        assert_eq!(40, number_digits(I256::new(10).pow(39)));
+    }
+}
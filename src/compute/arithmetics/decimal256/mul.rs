@@ -0,0 +1,271 @@
+//! Defines the multiplication arithmetic kernels for Decimal256
+//! `PrimitiveArrays`.
+
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayCheckedMul, ArrayMul, ArraySaturatingMul},
+        arity::{binary, binary_checked, unary},
+        utils::{check_same_len, combine_validities},
+    },
+    datatypes::DataType,
+    error::Result,
+    scalar::{PrimitiveScalar, Scalar},
+    types::i256,
+};
+
+use super::{
+    adapt_precision, adjusted_precision_scale, get_adaptive_parameters, get_parameters, max_value,
+    power_of_ten, MAX_PRECISION,
+};
+
+/// Multiply two decimal256 primitive arrays with the same precision and scale. If
+/// the precision and scale is different, then an InvalidArgumentError is
+/// returned. This function panics if the multiplied numbers result in a number
+/// larger than the possible number for the selected precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::mul;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 1_50)), None]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 2_00)), None]).to(DataType::Decimal256(5, 2));
+///
+/// let result = mul(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 3_00)), None]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn mul(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        // The multiplication between i256 can overflow if they are
+        // very large numbers. For that reason a checked
+        // multiplication is used.
+        let res =
+            a.0.checked_mul(b.0)
+                .expect("Mayor overflow for multiplication");
+
+        // The multiplication is done using the numbers without scale.
+        // The resulting scale of the value has to be corrected by
+        // dividing by (10^scale)
+        let res = res / scale;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in multiplication presented for precision {precision}"
+        );
+
+        i256(res)
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Multiply a decimal256 [`PrimitiveArray`] with a [`PrimitiveScalar`] with the same precision and scale. If
+/// the precision and scale is different, then an InvalidArgumentError is
+/// returned. This function panics if the multiplied numbers result in a number
+/// larger than the possible number for the selected precision.
+pub fn mul_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = *rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i256>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256| {
+        let res =
+            a.0.checked_mul(rhs.0)
+                .expect("Mayor overflow for multiplication");
+
+        let res = res / scale;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in multiplication presented for precision {precision}"
+        );
+
+        i256(res)
+    };
+
+    unary(lhs, op, lhs.data_type().clone())
+}
+
+/// Saturated multiplication of two decimal256 primitive arrays with the same
+/// precision and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the multiplication is
+/// larger than the possible number with the selected precision then the
+/// resulted number in the arrow array is the maximum number for the selected
+/// precision.
+pub fn saturating_mul(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| match a.0.checked_mul(b.0) {
+        Some(res) => {
+            let res = res / scale;
+
+            match res {
+                res if res.abs() > max => {
+                    if res > 0 {
+                        i256(max)
+                    } else {
+                        i256(-max)
+                    }
+                }
+                _ => i256(res),
+            }
+        }
+        None => {
+            if (a.0 < 0) != (b.0 < 0) {
+                i256(-max)
+            } else {
+                i256(max)
+            }
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked multiplication of two decimal256 primitive arrays with the same
+/// precision and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the mul is larger than
+/// the possible number with the selected precision (overflowing), then the
+/// validity for that index is changed to None
+pub fn checked_mul(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = power_of_ten(scale);
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| match a.0.checked_mul(b.0) {
+        Some(res) => {
+            let res = res / scale;
+
+            match res {
+                res if res.abs() > max => None,
+                _ => Some(i256(res)),
+            }
+        }
+        None => None,
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArrayMul trait for PrimitiveArrays
+impl ArrayMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        mul(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedMul trait for PrimitiveArrays
+impl ArrayCheckedMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_mul(self, rhs)
+    }
+}
+
+// Implementation of ArraySaturatingMul trait for PrimitiveArrays
+impl ArraySaturatingMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_mul(self, rhs)
+    }
+}
+
+/// Adaptive multiplication of two decimal256 primitive arrays with different
+/// precision and scale. If the precision and scale is different, then the
+/// smallest scale and precision is adjusted to the largest precision and
+/// scale. If during the multiplication one of the results is larger than the
+/// max possible value, the result precision is changed to the precision of the
+/// max value
+///
+/// ```nocode
+///   11111.0    -> 6, 1
+///      10.002  -> 5, 3
+/// -----------------
+///  111132.222  -> 9, 3
+/// ```
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::adaptive_mul;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 11111_0))]).to(DataType::Decimal256(6, 1));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 10_002))]).to(DataType::Decimal256(5, 3));
+/// let result = adaptive_mul(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 111132_222))]).to(DataType::Decimal256(9, 3));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn adaptive_mul(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    check_same_len(lhs, rhs)?;
+
+    let (lhs_p, lhs_s, rhs_p, rhs_s) = get_adaptive_parameters(lhs.data_type(), rhs.data_type())?;
+
+    // The resulting precision is mutable because it could change while
+    // looping through the iterator
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let mut res_p = res_p.min(MAX_PRECISION);
+
+    let shift = power_of_ten(diff);
+    let shift_1 = power_of_ten(res_s);
+    let mut max = max_value(res_p);
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(l, r)| {
+            // Based on the array's scales one of the arguments in the sum has to be shifted
+            // to the left to match the final scale
+            let res = if lhs_s > rhs_s {
+                l.0.checked_mul(r.0 * shift)
+            } else {
+                (l.0 * shift).checked_mul(r.0)
+            }
+            .expect("Mayor overflow for multiplication");
+
+            let res = res / shift_1;
+
+            // The precision of the resulting array will change if one of the
+            // multiplications during the iteration produces a value bigger
+            // than the possible value for the initial precision
+            adapt_precision(res, &mut res_p, &mut max)?;
+
+            Ok(i256(res))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(res_p, res_s),
+        values.into(),
+        validity,
+    ))
+}
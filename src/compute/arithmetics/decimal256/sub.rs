@@ -0,0 +1,204 @@
+//! Defines the subtract arithmetic kernels for Decimal256 `PrimitiveArrays`.
+
+use crate::{
+    array::PrimitiveArray,
+    compute::{
+        arithmetics::{ArrayCheckedSub, ArraySaturatingSub, ArraySub},
+        arity::{binary, binary_checked},
+        utils::{check_same_len, combine_validities},
+    },
+    datatypes::DataType,
+    error::Result,
+    types::i256,
+};
+
+use super::{
+    adapt_precision, adjusted_precision_scale, get_adaptive_parameters, get_parameters, max_value,
+    power_of_ten, MAX_PRECISION,
+};
+
+/// Subtract two decimal256 primitive arrays with the same precision and scale. If
+/// the precision and scale is different, then an InvalidArgumentError is
+/// returned. This function panics if the subtracted numbers result in a number
+/// smaller than the possible number for the selected precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::sub;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 3)), None]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 2)), None]).to(DataType::Decimal256(5, 2));
+///
+/// let result = sub(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 1)), None]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        let res = a.0 - b.0;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in subtract presented for precision {precision}"
+        );
+
+        i256(res)
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Saturated subtraction of two decimal256 primitive arrays with the same
+/// precision and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the sum is smaller
+/// than the possible number with the selected precision then the resulted
+/// number in the arrow array is the minimum number for the selected precision.
+pub fn saturating_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        let res = a.0 - b.0;
+
+        if res.abs() > max {
+            if res > 0 {
+                i256(max)
+            } else {
+                i256(-max)
+            }
+        } else {
+            i256(res)
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked subtract of two decimal256 primitive arrays with the same precision
+/// and scale. If the precision and scale is different, then an
+/// InvalidArgumentError is returned. If the result from the sub is larger than
+/// the possible number with the selected precision (overflowing), then the
+/// validity for that index is changed to None
+pub fn checked_sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+
+    let op = move |a: i256, b: i256| {
+        let res = a.0 - b.0;
+
+        if res.abs() > max {
+            None
+        } else {
+            Some(i256(res))
+        }
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+// Implementation of ArraySub trait for PrimitiveArrays
+impl ArraySub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        sub(self, rhs)
+    }
+}
+
+// Implementation of ArrayCheckedSub trait for PrimitiveArrays
+impl ArrayCheckedSub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_sub(self, rhs)
+    }
+}
+
+// Implementation of ArraySaturatingSub trait for PrimitiveArrays
+impl ArraySaturatingSub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_sub(self, rhs)
+    }
+}
+
+/// Adaptive subtract of two decimal256 primitive arrays with different precision
+/// and scale. If the precision and scale is different, then the smallest scale
+/// and precision is adjusted to the largest precision and scale. If during the
+/// subtraction one of the results is smaller than the min possible value, the
+/// result precision is changed to the precision of the min value
+///
+/// ```nocode
+///  99.9999 -> 6, 4
+/// -00.0001 -> 6, 4
+/// -----------------
+/// 100.0000 -> 7, 4
+/// ```
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal256::adaptive_sub;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 99_9999))]).to(DataType::Decimal256(6, 4));
+/// let b = PrimitiveArray::from([Some(i256::from_words(-1, -1))]).to(DataType::Decimal256(6, 4));
+/// let result = adaptive_sub(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 100_0000))]).to(DataType::Decimal256(7, 4));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn adaptive_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    check_same_len(lhs, rhs)?;
+
+    let (lhs_p, lhs_s, rhs_p, rhs_s) = get_adaptive_parameters(lhs.data_type(), rhs.data_type())?;
+
+    // The resulting precision is mutable because it could change while
+    // looping through the iterator
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let mut res_p = res_p.min(MAX_PRECISION);
+
+    let shift = power_of_ten(diff);
+    let mut max = max_value(res_p);
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(l, r)| {
+            // Based on the array's scales one of the arguments in the sum has to be shifted
+            // to the left to match the final scale
+            let res = if lhs_s > rhs_s {
+                l.0 - r.0 * shift
+            } else {
+                l.0 * shift - r.0
+            };
+
+            // The precision of the resulting array will change if one of the
+            // subtraction during the iteration produces a value bigger than the
+            // possible value for the initial precision
+            adapt_precision(res, &mut res_p, &mut max)?;
+
+            Ok(i256(res))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(res_p, res_s),
+        values.into(),
+        validity,
+    ))
+}
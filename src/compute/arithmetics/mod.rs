@@ -9,12 +9,15 @@
 //! * checked: turns an overflowing to a null.
 //! * saturating: turns the overflowing to the MAX or MIN value respectively.
 //! * overflowing: returns an extra [`Bitmap`] denoting whether the operation overflowed.
-//! * adaptive: for [`Decimal`](crate::datatypes::DataType::Decimal) only,
-//!   adjusts the precision and scale to make the resulting value fit.
+//! * adaptive: for [`Decimal`](crate::datatypes::DataType::Decimal) and
+//!   [`Decimal256`](crate::datatypes::DataType::Decimal256) only, adjusts the
+//!   precision and scale to make the resulting value fit.
 #[forbid(unsafe_code)]
 pub mod basic;
 #[cfg(feature = "compute_arithmetics_decimal")]
 pub mod decimal;
+#[cfg(feature = "compute_arithmetics_decimal")]
+pub mod decimal256;
 pub mod time;
 
 use crate::{
@@ -38,7 +41,7 @@ fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> Pr
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, decimal256 = $op_decimal256:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -63,6 +66,25 @@ macro_rules! arith {
             }
             )?
             $ (
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(decimal256::$op_decimal256(lhs, rhs)) as Box<dyn Array>
+            }
+            (Decimal(_, _), Decimal256(_, _)) => {
+                let lhs = decimal256::promote(lhs.as_any().downcast_ref().unwrap()).unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                let (lhs, rhs) = decimal256::promote_to_common(&lhs, rhs).unwrap();
+                Box::new(decimal256::$op_decimal256(&lhs, &rhs)) as Box<dyn Array>
+            }
+            (Decimal256(_, _), Decimal(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = decimal256::promote(rhs.as_any().downcast_ref().unwrap()).unwrap();
+                let (lhs, rhs) = decimal256::promote_to_common(lhs, &rhs).unwrap();
+                Box::new(decimal256::$op_decimal256(&lhs, &rhs)) as Box<dyn Array>
+            }
+            )?
+            $ (
             (Time32(TimeUnit::Second), Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
             | (Date32, Duration(_)) => {
@@ -128,7 +150,7 @@ fn binary_scalar_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &T) -> PrimitiveAr
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith_scalar {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, decimal256 = $op_decimal256:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -153,6 +175,13 @@ macro_rules! arith_scalar {
             }
             )?
             $ (
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                decimal256::$op_decimal256(lhs, rhs).boxed()
+            }
+            )?
+            $ (
             (Time32(TimeUnit::Second), Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
             | (Date32, Duration(_)) => {
@@ -197,12 +226,15 @@ macro_rules! arith_scalar {
 /// This function panics iff
 /// * the operation is not supported for the logical types (use [`can_add`] to check)
 /// * the arrays have a different length
+/// * the common precision of a `Decimal` and a `Decimal256` array is larger than 76
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
 pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
     arith!(
         lhs,
         rhs,
         add,
+        decimal = add,
+        decimal256 = add,
         duration = add_duration,
         interval = add_interval
     )
@@ -241,6 +273,9 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Float32, Float32)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
+            | (Decimal(_, _), Decimal256(_, _))
+            | (Decimal256(_, _), Decimal(_, _))
             | (Date32, Duration(_))
             | (Date64, Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
@@ -257,6 +292,7 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_sub`] to check)
 /// * the arrays have a different length
+/// * the common precision of a `Decimal` and a `Decimal256` array is larger than 76
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
 pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
     arith!(
@@ -264,6 +300,7 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         rhs,
         sub,
         decimal = sub,
+        decimal256 = sub,
         duration = subtract_duration,
         timestamp = subtract_timestamps
     )
@@ -302,6 +339,9 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Float32, Float32)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
+            | (Decimal(_, _), Decimal256(_, _))
+            | (Decimal256(_, _), Decimal(_, _))
             | (Date32, Duration(_))
             | (Date64, Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
@@ -318,8 +358,9 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 /// * the arrays have a different length
+/// * the common precision of a `Decimal` and a `Decimal256` array is larger than 76
 pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, mul, decimal = mul)
+    arith!(lhs, rhs, mul, decimal = mul, decimal256 = mul)
 }
 
 /// Multiply an [`Array`] with a [`Scalar`].
@@ -327,7 +368,13 @@ pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 pub fn mul_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
-    arith_scalar!(lhs, rhs, mul_scalar, decimal = mul_scalar)
+    arith_scalar!(
+        lhs,
+        rhs,
+        mul_scalar,
+        decimal = mul_scalar,
+        decimal256 = mul_scalar
+    )
 }
 
 /// Returns whether two [`DataType`]s can be multiplied by [`mul`].
@@ -346,6 +393,9 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
             | (Float64, Float64)
             | (Float32, Float32)
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
+            | (Decimal(_, _), Decimal256(_, _))
+            | (Decimal256(_, _), Decimal(_, _))
     )
 }
 
@@ -354,8 +404,9 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 /// * the arrays have a different length
+/// * the common precision of a `Decimal` and a `Decimal256` array is larger than 76
 pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, div, decimal = div)
+    arith!(lhs, rhs, div, decimal = div, decimal256 = div)
 }
 
 /// Divide an [`Array`] with a [`Scalar`].
@@ -363,7 +414,13 @@ pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 pub fn div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
-    arith_scalar!(lhs, rhs, div_scalar, decimal = div_scalar)
+    arith_scalar!(
+        lhs,
+        rhs,
+        div_scalar,
+        decimal = div_scalar,
+        decimal256 = div_scalar
+    )
 }
 
 /// Returns whether two [`DataType`]s can be divided by [`div`].
@@ -0,0 +1,152 @@
+//! Comparison functions for [`DictionaryArray`]s.
+//!
+//! A [`DictionaryArray`] can be compared against another [`DictionaryArray`] (with possibly
+//! different keys) or against an array of its values' [`DataType`]. The comparison is
+//! performed on the values indexed by the keys, without unpacking the dictionary.
+use std::cmp::Ordering;
+
+use crate::array::{ord::build_compare, Array, BooleanArray, DictionaryArray};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, PhysicalType};
+
+use super::super::utils::combine_validities;
+use super::nested::build_eq;
+
+type Index<'a> = Box<dyn Fn(usize) -> usize + 'a>;
+
+/// Returns the [`DataType`] of the values of `data_type` if it is a
+/// [`DataType::Dictionary`], or `data_type` otherwise.
+pub(super) fn value_type(data_type: &DataType) -> &DataType {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        values.as_ref()
+    } else {
+        data_type
+    }
+}
+
+/// Splits `array` into its values, a function mapping each slot to a slot of the values,
+/// and the slots' validity.
+/// A slot of a [`DictionaryArray`] is null if its key is null or if it points to a null value.
+fn split(array: &dyn Array) -> (&dyn Array, Index, Option<Bitmap>) {
+    if let PhysicalType::Dictionary(key_type) = array.data_type().to_physical_type() {
+        match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = array.values().as_ref();
+            let validity = match values.validity() {
+                Some(bitmap) if bitmap.unset_bits() > 0 => {
+                    Some(Bitmap::from_iter((0..array.len()).map(|i| {
+                        array.is_valid(i) && bitmap.get_bit(array.key_value(i))
+                    })))
+                }
+                _ => array.validity().cloned(),
+            };
+            (values, Box::new(move |i| array.key_value(i)) as Index, validity)
+        })
+    } else {
+        (array, Box::new(|i| i), array.validity().cloned())
+    }
+}
+
+fn compare_op<F>(lhs: &dyn Array, rhs: &dyn Array, op: F) -> BooleanArray
+where
+    F: Fn(Ordering) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let (lhs_values, lhs_index, lhs_validity) = split(lhs);
+    let (rhs_values, rhs_index, rhs_validity) = split(rhs);
+    let cmp = build_compare(lhs_values, rhs_values).unwrap();
+
+    let values = Bitmap::from_iter((0..lhs.len()).map(|i| op(cmp(lhs_index(i), rhs_index(i)))));
+    let validity = combine_validities(lhs_validity.as_ref(), rhs_validity.as_ref());
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+fn eq_op(lhs: &dyn Array, rhs: &dyn Array, negate: bool, and_validity: bool) -> BooleanArray {
+    assert_eq!(lhs.len(), rhs.len());
+    let (lhs_values, lhs_index, lhs_validity) = split(lhs);
+    let (rhs_values, rhs_index, rhs_validity) = split(rhs);
+    let eq = build_eq(lhs_values, rhs_values).unwrap();
+
+    let is_valid = |validity: &Option<Bitmap>, i: usize| {
+        validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true)
+    };
+
+    if and_validity {
+        let values = Bitmap::from_iter((0..lhs.len()).map(|i| {
+            let is_eq = match (is_valid(&lhs_validity, i), is_valid(&rhs_validity, i)) {
+                (true, true) => eq(lhs_index(i), rhs_index(i)),
+                (false, false) => true,
+                _ => false,
+            };
+            is_eq != negate
+        }));
+        BooleanArray::new(DataType::Boolean, values, None)
+    } else {
+        let values =
+            Bitmap::from_iter((0..lhs.len()).map(|i| eq(lhs_index(i), rhs_index(i)) != negate));
+        let validity = combine_validities(lhs_validity.as_ref(), rhs_validity.as_ref());
+        BooleanArray::new(DataType::Boolean, values, validity)
+    }
+}
+
+/// Perform `lhs == rhs` operation where at least one of the arrays is a [`DictionaryArray`].
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`] or the arrays have different lengths.
+pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    eq_op(lhs, rhs, false, false)
+}
+
+/// Perform `lhs == rhs` operation where at least one of the arrays is a [`DictionaryArray`]
+/// and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`] or the arrays have different lengths.
+pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    eq_op(lhs, rhs, false, true)
+}
+
+/// Perform `lhs != rhs` operation where at least one of the arrays is a [`DictionaryArray`].
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`] or the arrays have different lengths.
+pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    eq_op(lhs, rhs, true, false)
+}
+
+/// Perform `lhs != rhs` operation where at least one of the arrays is a [`DictionaryArray`]
+/// and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`] or the arrays have different lengths.
+pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    eq_op(lhs, rhs, true, true)
+}
+
+/// Perform `lhs < rhs` operation where at least one of the arrays is a [`DictionaryArray`].
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`], the arrays have different
+/// lengths or the values have no natural order.
+pub fn lt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x == Ordering::Less)
+}
+
+/// Perform `lhs <= rhs` operation where at least one of the arrays is a [`DictionaryArray`].
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`], the arrays have different
+/// lengths or the values have no natural order.
+pub fn lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x != Ordering::Greater)
+}
+
+/// Perform `lhs > rhs` operation where at least one of the arrays is a [`DictionaryArray`].
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`], the arrays have different
+/// lengths or the values have no natural order.
+pub fn gt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x == Ordering::Greater)
+}
+
+/// Perform `lhs >= rhs` operation where at least one of the arrays is a [`DictionaryArray`].
+/// # Panic
+/// Panics iff the arrays' values do not have the same [`DataType`], the arrays have different
+/// lengths or the values have no natural order.
+pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x != Ordering::Less)
+}
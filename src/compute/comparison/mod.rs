@@ -46,10 +46,13 @@
 
 use crate::array::*;
 use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{Error, Result};
 use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod dictionary;
+pub mod nested;
 pub mod primitive;
 pub mod utf8;
 
@@ -69,7 +72,7 @@ macro_rules! match_eq_ord {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::i256;
+    use crate::types::{days_ms, months_days_ns, f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -77,13 +80,13 @@ macro_rules! match_eq_ord {(
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
         Int256 => __with_ty__! { i256 },
-        DaysMs => todo!(),
-        MonthDayNano => todo!(),
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
-        Float16 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -115,15 +118,21 @@ macro_rules! match_eq {(
 })}
 
 macro_rules! compare {
-    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt $(, $nested:ident)?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
+            dictionary::value_type(lhs.data_type()).to_logical_type(),
+            dictionary::value_type(rhs.data_type()).to_logical_type()
         );
 
         use crate::datatypes::PhysicalType::*;
+        if matches!(lhs.data_type().to_physical_type(), Dictionary(_))
+            || matches!(rhs.data_type().to_physical_type(), Dictionary(_))
+        {
+            return dictionary::$op(lhs, rhs);
+        }
+
         match lhs.data_type().to_physical_type() {
             Boolean => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
@@ -155,6 +164,7 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            $(List | LargeList | FixedSizeList | Struct | Map => $nested::$op(lhs, rhs),)?
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -171,7 +181,7 @@ macro_rules! compare {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq, match_eq)
+    compare!(lhs, rhs, eq, match_eq, nested)
 }
 
 /// `==` between two [`Array`]s and includes validities in comparison.
@@ -182,7 +192,7 @@ pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq_and_validity, match_eq)
+    compare!(lhs, rhs, eq_and_validity, match_eq, nested)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`eq`].
@@ -198,7 +208,7 @@ pub fn can_eq(data_type: &DataType) -> bool {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq, match_eq)
+    compare!(lhs, rhs, neq, match_eq, nested)
 }
 
 /// `!=` between two [`Array`]s and includes validities in comparison.
@@ -209,7 +219,7 @@ pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq_and_validity, match_eq)
+    compare!(lhs, rhs, neq_and_validity, match_eq, nested)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`neq`].
@@ -217,6 +227,40 @@ pub fn can_neq(data_type: &DataType) -> bool {
     can_partial_eq(data_type)
 }
 
+/// SQL's `IS NOT DISTINCT FROM` between two [`Array`]s: `==` where two nulls are equal and
+/// a null is different from any value. The result has no nulls.
+/// Use [`can_is_not_distinct_from`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn is_not_distinct_from(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    eq_and_validity(lhs, rhs)
+}
+
+/// Returns whether a [`DataType`] is supported by [`is_not_distinct_from`].
+pub fn can_is_not_distinct_from(data_type: &DataType) -> bool {
+    can_eq(data_type)
+}
+
+/// SQL's `IS DISTINCT FROM` between two [`Array`]s: `!=` where two nulls are equal and
+/// a null is different from any value. The result has no nulls.
+/// Use [`can_is_distinct_from`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn is_distinct_from(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    neq_and_validity(lhs, rhs)
+}
+
+/// Returns whether a [`DataType`] is supported by [`is_distinct_from`].
+pub fn can_is_distinct_from(data_type: &DataType) -> bool {
+    can_neq(data_type)
+}
+
 /// `<` between two [`Array`]s.
 /// Use [`can_lt`] to check whether the operation is valid
 /// # Panic
@@ -283,17 +327,28 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
 
 macro_rules! compare_scalar {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+        use crate::datatypes::PhysicalType::*;
         let lhs = $lhs;
         let rhs = $rhs;
+        // a `DictionaryScalar` is compared via its value
+        let rhs = if let Dictionary(key_type) = rhs.data_type().to_physical_type() {
+            match_integer_type!(key_type, |$T| {
+                let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+                match rhs.value() {
+                    Some(value) => value.as_ref(),
+                    None => return BooleanArray::new_null(DataType::Boolean, lhs.len()),
+                }
+            })
+        } else {
+            rhs
+        };
         assert_eq!(
-            lhs.data_type().to_logical_type(),
+            dictionary::value_type(lhs.data_type()).to_logical_type(),
             rhs.data_type().to_logical_type()
         );
         if !rhs.is_valid() {
             return BooleanArray::new_null(DataType::Boolean, lhs.len());
         }
-
-        use crate::datatypes::PhysicalType::*;
         match lhs.data_type().to_physical_type() {
             Boolean => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
@@ -389,6 +444,61 @@ pub fn can_neq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_scalar(data_type)
 }
 
+// Sets the null slots of `array` to `value`, so that the result has no nulls.
+fn fill_nulls(array: BooleanArray, value: bool) -> BooleanArray {
+    match array.validity() {
+        Some(validity) => {
+            let values = if value {
+                array.values() | &!validity
+            } else {
+                array.values() & validity
+            };
+            BooleanArray::new(DataType::Boolean, values, None)
+        }
+        None => array,
+    }
+}
+
+/// SQL's `IS NOT DISTINCT FROM` between an [`Array`] and a [`Scalar`]: `==` where two nulls
+/// are equal and a null is different from any value. The result has no nulls.
+/// Use [`can_is_not_distinct_from_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn is_not_distinct_from_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if rhs.is_valid() {
+        fill_nulls(eq_scalar(lhs, rhs), false)
+    } else {
+        compute::boolean::is_null(lhs)
+    }
+}
+
+/// Returns whether a [`DataType`] is supported by [`is_not_distinct_from_scalar`].
+pub fn can_is_not_distinct_from_scalar(data_type: &DataType) -> bool {
+    can_eq_scalar(data_type)
+}
+
+/// SQL's `IS DISTINCT FROM` between an [`Array`] and a [`Scalar`]: `!=` where two nulls
+/// are equal and a null is different from any value. The result has no nulls.
+/// Use [`can_is_distinct_from_scalar`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn is_distinct_from_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if rhs.is_valid() {
+        fill_nulls(neq_scalar(lhs, rhs), true)
+    } else {
+        compute::boolean::is_not_null(lhs)
+    }
+}
+
+/// Returns whether a [`DataType`] is supported by [`is_distinct_from_scalar`].
+pub fn can_is_distinct_from_scalar(data_type: &DataType) -> bool {
+    can_neq_scalar(data_type)
+}
+
 /// `<` between an [`Array`] and a [`Scalar`].
 /// Use [`can_lt_scalar`] to check whether the operation is valid
 /// # Panic
@@ -449,6 +559,104 @@ pub fn can_gt_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_and_ord_scalar(data_type)
 }
 
+/// `low <= array <= high` (or `low < array < high` when not `inclusive`) between three
+/// [`Array`]s, evaluated in a single pass.
+/// Use [`can_between`] to check whether the operation is valid
+/// # Error
+/// Errors iff the operation is not supported for the logical type, i.e. boolean, dictionary
+/// and nested types
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+pub fn between(
+    array: &dyn Array,
+    low: &dyn Array,
+    high: &dyn Array,
+    inclusive: bool,
+) -> Result<BooleanArray> {
+    let data_type = array.data_type().to_logical_type();
+    assert_eq!(data_type, low.data_type().to_logical_type());
+    assert_eq!(data_type, high.data_type().to_logical_type());
+
+    macro_rules! between {
+        ($module:ident, $($ty:tt)*) => {{
+            let array = array.as_any().downcast_ref().unwrap();
+            let low = low.as_any().downcast_ref().unwrap();
+            let high = high.as_any().downcast_ref().unwrap();
+            Ok($module::between::<$($ty)*>(array, low, high, inclusive))
+        }};
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Primitive(primitive) => match_eq_ord!(primitive, |$T| between!(primitive, $T)),
+        Utf8 => between!(utf8, i32),
+        LargeUtf8 => between!(utf8, i64),
+        Binary => between!(binary, i32),
+        LargeBinary => between!(binary, i64),
+        _ => Err(Error::NotYetImplemented(format!(
+            "between of {:?} is not yet supported",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns whether a [`DataType`] is supported by [`between`] and [`between_scalar`].
+pub fn can_between(data_type: &DataType) -> bool {
+    !matches!(
+        data_type.to_logical_type(),
+        DataType::Boolean | DataType::Dictionary(_, _, _)
+    ) && can_partial_eq_and_ord(data_type)
+}
+
+/// `low <= array <= high` (or `low < array < high` when not `inclusive`) between an
+/// [`Array`] and two [`Scalar`]s, evaluated in a single pass.
+/// Use [`can_between`] to check whether the operation is valid
+/// # Error
+/// Errors iff the operation is not supported for the logical type, i.e. boolean, dictionary
+/// and nested types
+/// # Panic
+/// Panics iff they do not have have the same logical type
+pub fn between_scalar(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+    inclusive: bool,
+) -> Result<BooleanArray> {
+    let data_type = array.data_type().to_logical_type();
+    assert_eq!(data_type, low.data_type().to_logical_type());
+    assert_eq!(data_type, high.data_type().to_logical_type());
+
+    macro_rules! between_scalar {
+        ($module:ident, $scalar:ty, $($ty:tt)*) => {{
+            if !low.is_valid() || !high.is_valid() {
+                return Ok(BooleanArray::new_null(DataType::Boolean, array.len()));
+            }
+            let array = array.as_any().downcast_ref().unwrap();
+            // validity checked above
+            let low = low.as_any().downcast_ref::<$scalar>().unwrap().value().unwrap();
+            let high = high.as_any().downcast_ref::<$scalar>().unwrap().value().unwrap();
+            Ok($module::between_scalar::<$($ty)*>(array, low, high, inclusive))
+        }};
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Primitive(primitive) => match_eq_ord!(primitive, |$T| {
+            between_scalar!(primitive, PrimitiveScalar<$T>, $T)
+        }),
+        Utf8 => between_scalar!(utf8, Utf8Scalar<i32>, i32),
+        LargeUtf8 => between_scalar!(utf8, Utf8Scalar<i64>, i64),
+        Binary => between_scalar!(binary, BinaryScalar<i32>, i32),
+        LargeBinary => between_scalar!(binary, BinaryScalar<i64>, i64),
+        _ => Err(Error::NotYetImplemented(format!(
+            "between of {:?} is not yet supported",
+            array.data_type()
+        ))),
+    }
+}
+
 // The list of operations currently supported.
 fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
     if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
@@ -459,6 +667,9 @@ fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        return can_partial_eq_and_ord(values.as_ref());
+    }
     matches!(
         data_type,
         DataType::Boolean
@@ -468,6 +679,8 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::Date32
             | DataType::Time32(_)
             | DataType::Interval(IntervalUnit::YearMonth)
+            | DataType::Interval(IntervalUnit::DayTime)
+            | DataType::Interval(IntervalUnit::MonthDayNano)
             | DataType::Int64
             | DataType::Timestamp(_, _)
             | DataType::Date64
@@ -477,11 +690,13 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Binary
             | DataType::LargeBinary
     )
@@ -490,22 +705,35 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
 // The list of operations currently supported.
 fn can_partial_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
-        || matches!(
+        || (matches!(
             data_type.to_logical_type(),
-            DataType::Float16
-                | DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
-        )
+            DataType::List(_)
+                | DataType::LargeList(_)
+                | DataType::FixedSizeList(_, _)
+                | DataType::Struct(_)
+                | DataType::Map(_, _)
+                | DataType::Dictionary(_, _, _)
+        ) && can_eq_nested(data_type))
+}
+
+// Whether values of `data_type` can be compared via structural equality,
+// see [`nested::build_eq`].
+fn can_eq_nested(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => can_eq_nested(field.data_type()),
+        DataType::Struct(fields) => fields.iter().all(|field| can_eq_nested(field.data_type())),
+        DataType::Dictionary(_, values, _) => can_eq_nested(values.as_ref()),
+        DataType::Union(_, _, _) => false,
+        _ => true,
+    }
 }
 
 // The list of operations currently supported.
 fn can_partial_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_and_ord_scalar(data_type)
-        || matches!(
-            data_type.to_logical_type(),
-            DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
-        )
 }
 
 /// Utility for low level end users that implement their own comparison functions
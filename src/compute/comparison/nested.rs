@@ -0,0 +1,260 @@
+//! Comparison functions for nested arrays ([`StructArray`], [`ListArray`],
+//! [`FixedSizeListArray`] and [`MapArray`]).
+//!
+//! Nested arrays are compared element-wise via structural equality: two slots are
+//! equal iff all their children are equal, where two null children are considered equal.
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::offset::{Offset, OffsetsBuffer};
+use crate::types::NativeType;
+
+use super::super::utils::combine_validities;
+
+/// Returns whether the values at two arbitrary indices in two arrays are equal.
+pub type DynEq = Box<dyn Fn(usize, usize) -> bool + Send + Sync>;
+
+fn eq_primitive<T: NativeType>(lhs: &dyn Array, rhs: &dyn Array) -> DynEq {
+    let lhs = lhs
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    let rhs = rhs
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+}
+
+fn eq_boolean(lhs: &dyn Array, rhs: &dyn Array) -> DynEq {
+    let lhs = lhs.as_any().downcast_ref::<BooleanArray>().unwrap().clone();
+    let rhs = rhs.as_any().downcast_ref::<BooleanArray>().unwrap().clone();
+    Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+}
+
+fn eq_utf8<O: Offset>(lhs: &dyn Array, rhs: &dyn Array) -> DynEq {
+    let lhs = lhs.as_any().downcast_ref::<Utf8Array<O>>().unwrap().clone();
+    let rhs = rhs.as_any().downcast_ref::<Utf8Array<O>>().unwrap().clone();
+    Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+}
+
+fn eq_binary<O: Offset>(lhs: &dyn Array, rhs: &dyn Array) -> DynEq {
+    let lhs = lhs
+        .as_any()
+        .downcast_ref::<BinaryArray<O>>()
+        .unwrap()
+        .clone();
+    let rhs = rhs
+        .as_any()
+        .downcast_ref::<BinaryArray<O>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+}
+
+fn eq_fixed_size_binary(lhs: &dyn Array, rhs: &dyn Array) -> DynEq {
+    let lhs = lhs
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap()
+        .clone();
+    let rhs = rhs
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+}
+
+fn eq_offsets<O: Offset>(
+    lhs: OffsetsBuffer<O>,
+    rhs: OffsetsBuffer<O>,
+    lhs_values: &dyn Array,
+    rhs_values: &dyn Array,
+) -> Result<DynEq> {
+    let values = build_eq(lhs_values, rhs_values)?;
+    Ok(Box::new(move |i, j| {
+        let (lhs_start, lhs_end) = lhs.start_end(i);
+        let (rhs_start, rhs_end) = rhs.start_end(j);
+        lhs_end - lhs_start == rhs_end - rhs_start
+            && (0..lhs_end - lhs_start).all(|k| values(lhs_start + k, rhs_start + k))
+    }))
+}
+
+fn eq_list<O: Offset>(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    let lhs = lhs.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    eq_offsets(
+        lhs.offsets().clone(),
+        rhs.offsets().clone(),
+        lhs.values().as_ref(),
+        rhs.values().as_ref(),
+    )
+}
+
+fn eq_map(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    let lhs = lhs.as_any().downcast_ref::<MapArray>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<MapArray>().unwrap();
+    eq_offsets(
+        lhs.offsets().clone(),
+        rhs.offsets().clone(),
+        lhs.field().as_ref(),
+        rhs.field().as_ref(),
+    )
+}
+
+fn eq_fixed_size_list(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    let lhs = lhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = lhs.size();
+    let values = build_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+    Ok(Box::new(move |i, j| {
+        (0..size).all(|k| values(i * size + k, j * size + k))
+    }))
+}
+
+fn eq_struct(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    let lhs = lhs.as_any().downcast_ref::<StructArray>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<StructArray>().unwrap();
+    let fields = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| build_eq(lhs.as_ref(), rhs.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(move |i, j| fields.iter().all(|field| field(i, j))))
+}
+
+fn eq_dictionary<K: DictionaryKey>(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    let lhs = lhs.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let lhs_keys = lhs.keys().values().clone();
+    let rhs_keys = rhs.keys().values().clone();
+    let values = build_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+    Ok(Box::new(move |i, j| {
+        // safety: all dictionaries keys are guaranteed to be castable to usize
+        let (i, j) = unsafe { (lhs_keys[i].as_usize(), rhs_keys[j].as_usize()) };
+        values(i, j)
+    }))
+}
+
+/// Returns a [`DynEq`] that compares the values at two slots between two [`Array`]s,
+/// ignoring their validities.
+fn build_eq_values(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    use PhysicalType::*;
+    Ok(match lhs.data_type().to_physical_type() {
+        Null => Box::new(|_, _| true),
+        Boolean => eq_boolean(lhs, rhs),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            eq_primitive::<$T>(lhs, rhs)
+        }),
+        Utf8 => eq_utf8::<i32>(lhs, rhs),
+        LargeUtf8 => eq_utf8::<i64>(lhs, rhs),
+        Binary => eq_binary::<i32>(lhs, rhs),
+        LargeBinary => eq_binary::<i64>(lhs, rhs),
+        FixedSizeBinary => eq_fixed_size_binary(lhs, rhs),
+        List => eq_list::<i32>(lhs, rhs)?,
+        LargeList => eq_list::<i64>(lhs, rhs)?,
+        FixedSizeList => eq_fixed_size_list(lhs, rhs)?,
+        Struct => eq_struct(lhs, rhs)?,
+        Map => eq_map(lhs, rhs)?,
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            eq_dictionary::<$T>(lhs, rhs)?
+        }),
+        Union => {
+            return Err(Error::NotYetImplemented(
+                "Equality of union arrays is not yet supported".to_string(),
+            ))
+        }
+    })
+}
+
+/// Returns a [`DynEq`] that compares the values at two arbitrary slots between two [`Array`]s.
+///
+/// Nested values are compared structurally and nulls are considered equal to each other,
+/// e.g. `[1, None] == [1, None]`.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::comparison::nested::build_eq;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), None]), Some(vec![Some(1), None])])?;
+/// let array: ListArray<i32> = array.into();
+///
+/// let eq = build_eq(&array, &array)?;
+/// assert!((eq)(0, 1));
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff the arrays do not have the same [`DataType`] or the type is not supported.
+pub fn build_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(Error::InvalidArgumentError(
+            "Can't compare arrays of different types".to_string(),
+        ));
+    }
+    if lhs.data_type() == &DataType::Null {
+        return Ok(Box::new(|_, _| true));
+    }
+
+    let values = build_eq_values(lhs, rhs)?;
+    Ok(match (lhs.validity().cloned(), rhs.validity().cloned()) {
+        (None, None) => values,
+        (Some(lhs), None) => Box::new(move |i, j| lhs.get_bit(i) && values(i, j)),
+        (None, Some(rhs)) => Box::new(move |i, j| rhs.get_bit(j) && values(i, j)),
+        (Some(lhs), Some(rhs)) => Box::new(move |i, j| match (lhs.get_bit(i), rhs.get_bit(j)) {
+            (true, true) => values(i, j),
+            (false, false) => true,
+            _ => false,
+        }),
+    })
+}
+
+fn compare_op(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    negate: bool,
+    validity: Option<Bitmap>,
+) -> BooleanArray {
+    assert_eq!(lhs.len(), rhs.len());
+    let eq = build_eq(lhs, rhs).unwrap();
+
+    let values = Bitmap::from_iter((0..lhs.len()).map(|i| eq(i, i) != negate));
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `lhs == rhs` operation on two nested [`Array`]s.
+/// # Panic
+/// Panics iff the arrays do not have the same [`DataType`] or length.
+pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    compare_op(lhs, rhs, false, validity)
+}
+
+/// Perform `lhs == rhs` operation on two nested [`Array`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same [`DataType`] or length.
+pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    compare_op(lhs, rhs, false, None)
+}
+
+/// Perform `lhs != rhs` operation on two nested [`Array`]s.
+/// # Panic
+/// Panics iff the arrays do not have the same [`DataType`] or length.
+pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    compare_op(lhs, rhs, true, validity)
+}
+
+/// Perform `lhs != rhs` operation on two nested [`Array`]s and include validities in comparison.
+/// # Panic
+/// Panics iff the arrays do not have the same [`DataType`] or length.
+pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    compare_op(lhs, rhs, true, None)
+}
@@ -260,6 +260,114 @@ where
     compare_op_scalar(lhs, rhs, |a, b| a.gt_eq(b))
 }
 
+/// Evaluate `low <= x <= high` (or `low < x < high` when not `inclusive`) over three slices
+/// in a single pass.
+fn between_values<T>(values: &[T], low: &[T], high: &[T], inclusive: bool) -> MutableBitmap
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    assert_eq!(values.len(), low.len());
+    assert_eq!(values.len(), high.len());
+    let op = |x: T::Simd, low: T::Simd, high: T::Simd| {
+        if inclusive {
+            x.gt_eq(low) & x.lt_eq(high)
+        } else {
+            x.gt(low) & x.lt(high)
+        }
+    };
+
+    let chunks = values
+        .chunks_exact(8)
+        .zip(low.chunks_exact(8))
+        .zip(high.chunks_exact(8));
+    let remainder = values.len() % 8;
+
+    let mut bytes = Vec::with_capacity((values.len() + 7) / 8);
+    bytes.extend(chunks.map(|((x, low), high)| {
+        op(
+            T::Simd::from_chunk(x),
+            T::Simd::from_chunk(low),
+            T::Simd::from_chunk(high),
+        )
+    }));
+
+    if remainder != 0 {
+        let start = values.len() - remainder;
+        let from = |v: &[T]| T::Simd::from_incomplete_chunk(&v[start..], T::default());
+        bytes.push(op(from(values), from(low), from(high)))
+    };
+    MutableBitmap::from_vec(bytes, values.len())
+}
+
+/// Evaluate `low <= x <= high` (or `low < x < high` when not `inclusive`) over a slice
+/// and two scalars in a single pass.
+fn between_values_scalar<T>(values: &[T], low: T, high: T, inclusive: bool) -> MutableBitmap
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    let low = T::Simd::from_chunk(&[low; 8]);
+    let high = T::Simd::from_chunk(&[high; 8]);
+    let op = |x: T::Simd| {
+        if inclusive {
+            x.gt_eq(low) & x.lt_eq(high)
+        } else {
+            x.gt(low) & x.lt(high)
+        }
+    };
+
+    let chunks = values.chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    let mut bytes = Vec::with_capacity((values.len() + 7) / 8);
+    bytes.extend(chunks.map(|x| op(T::Simd::from_chunk(x))));
+
+    if !remainder.is_empty() {
+        bytes.push(op(T::Simd::from_incomplete_chunk(remainder, T::default())))
+    };
+    MutableBitmap::from_vec(bytes, values.len())
+}
+
+/// Perform `low <= array <= high` (or `low < array < high` when not `inclusive`) operation
+/// on three arrays in a single pass.
+/// # Panic
+/// Panics iff the arrays do not have the same length.
+pub fn between<T>(
+    array: &PrimitiveArray<T>,
+    low: &PrimitiveArray<T>,
+    high: &PrimitiveArray<T>,
+    inclusive: bool,
+) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let values = between_values(array.values(), low.values(), high.values(), inclusive);
+
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+/// Perform `low <= array <= high` (or `low < array < high` when not `inclusive`) operation
+/// on an array and two scalar values in a single pass.
+pub fn between_scalar<T>(
+    array: &PrimitiveArray<T>,
+    low: T,
+    high: T,
+    inclusive: bool,
+) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    let values = between_values_scalar(array.values(), low, high, inclusive);
+
+    BooleanArray::new(DataType::Boolean, values.into(), array.validity().cloned())
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -114,6 +114,33 @@ macro_rules! simd8_native_partial_ord {
     };
 }
 
+/// Types that do not implement PartialOrd but whose order is defined by a key that does
+macro_rules! simd8_native_partial_ord_by_key {
+    ($type:ty, $key:expr) => {
+        impl Simd8PartialOrd for [$type; 8] {
+            #[inline]
+            fn lt_eq(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) <= $key(y))
+            }
+
+            #[inline]
+            fn lt(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) < $key(y))
+            }
+
+            #[inline]
+            fn gt_eq(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) >= $key(y))
+            }
+
+            #[inline]
+            fn gt(self, other: Self) -> u8 {
+                set(self, other, |x, y| $key(x) > $key(y))
+            }
+        }
+    };
+}
+
 /// Types that implement simd8, PartialEq and PartialOrd
 macro_rules! simd8_native_all {
     ($type:ty) => {
@@ -74,9 +74,16 @@ simd8_native_all!(i128);
 simd8_native_all!(i256);
 simd8_native!(f16);
 simd8_native_partial_eq!(f16);
+simd8_native_partial_ord_by_key!(f16, |x: f16| x.to_f32());
 simd8!(f32, f32x8);
 simd8!(f64, f64x8);
 simd8_native!(days_ms);
 simd8_native_partial_eq!(days_ms);
+simd8_native_partial_ord_by_key!(days_ms, |x: days_ms| (x.days(), x.milliseconds()));
 simd8_native!(months_days_ns);
 simd8_native_partial_eq!(months_days_ns);
+simd8_native_partial_ord_by_key!(months_days_ns, |x: months_days_ns| (
+    x.months(),
+    x.days(),
+    x.ns()
+));
@@ -143,6 +143,57 @@ pub fn gt_eq_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
     compare_op_scalar(lhs, rhs, |a, b| a >= b)
 }
 
+/// Perform `low <= array <= high` (or `low < array < high` when not `inclusive`) operation
+/// on three [`Utf8Array`]s in a single pass.
+/// # Panic
+/// Panics iff the arrays do not have the same length.
+pub fn between<O: Offset>(
+    array: &Utf8Array<O>,
+    low: &Utf8Array<O>,
+    high: &Utf8Array<O>,
+    inclusive: bool,
+) -> BooleanArray {
+    assert_eq!(array.len(), low.len());
+    assert_eq!(array.len(), high.len());
+    let validity = combine_validities(array.validity(), low.validity());
+    let validity = combine_validities(validity.as_ref(), high.validity());
+
+    let values = array
+        .values_iter()
+        .zip(low.values_iter())
+        .zip(high.values_iter())
+        .map(|((x, low), high)| {
+            if inclusive {
+                low <= x && x <= high
+            } else {
+                low < x && x < high
+            }
+        });
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `low <= array <= high` (or `low < array < high` when not `inclusive`) operation
+/// on a [`Utf8Array`] and two scalar values in a single pass.
+pub fn between_scalar<O: Offset>(
+    array: &Utf8Array<O>,
+    low: &str,
+    high: &str,
+    inclusive: bool,
+) -> BooleanArray {
+    let values = array.values_iter().map(|x| {
+        if inclusive {
+            low <= x && x <= high
+        } else {
+            low < x && x < high
+        }
+    });
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
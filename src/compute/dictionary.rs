@@ -0,0 +1,348 @@
+//! Contains kernels to encode arrays as [`DictionaryArray`]s and to manage their values
+//! without decoding them.
+//!
+//! Apart from [`dictionary_encode`], all kernels return dictionaries whose keys represent the
+//! same values as their input, but whose values are re-arranged: unified across arrays,
+//! deduplicated, sorted or stripped from unreferenced values.
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::{
+    array::{
+        growable::make_growable,
+        indexable::{AsIndexed, Indexable},
+        ord::build_compare,
+        Array, BinaryArray, DictionaryArray, DictionaryKey, MutableArray, MutableBinaryArray,
+        MutableDictionaryArray, MutablePrimitiveArray, MutableUtf8Array, PrimitiveArray, TryPush,
+        UInt64Array, Utf8Array,
+    },
+    datatypes::{DataType, IntegerType, PhysicalType},
+    error::{Error, Result},
+    offset::Offset,
+    types::NativeType,
+};
+
+use super::take::take;
+
+/// Returns the positions of the distinct values of `values` in ascending order (with a single
+/// null last) and, for each value of `values`, the index of its distinct value.
+fn distinct(values: &dyn Array) -> Result<(Vec<usize>, Vec<usize>)> {
+    let cmp = build_compare(values, values)?;
+    let compare = |lhs: usize, rhs: usize| match (values.is_valid(lhs), values.is_valid(rhs)) {
+        (true, true) => cmp(lhs, rhs),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    };
+
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+    indices.sort_by(|lhs, rhs| compare(*lhs, *rhs));
+
+    let mut unique: Vec<usize> = Vec::with_capacity(values.len());
+    let mut positions = vec![0; values.len()];
+    for index in indices {
+        if unique
+            .last()
+            .map_or(true, |last| compare(*last, index) != Ordering::Equal)
+        {
+            unique.push(index);
+        }
+        positions[index] = unique.len() - 1;
+    }
+    Ok((unique, positions))
+}
+
+fn take_values(values: &dyn Array, indices: &[usize]) -> Result<Box<dyn Array>> {
+    let indices = UInt64Array::from_vec(indices.iter().map(|x| *x as u64).collect());
+    take(values, &indices)
+}
+
+/// Returns a new [`DictionaryArray`] with `values` whose keys are the keys of `array` mapped
+/// through `position`, the new position of each of the values of `array`.
+fn remap<K1: DictionaryKey, K2: DictionaryKey, F: Fn(usize) -> usize>(
+    array: &DictionaryArray<K1>,
+    position: F,
+    values: Box<dyn Array>,
+    is_ordered: bool,
+) -> Result<DictionaryArray<K2>> {
+    let keys = array
+        .keys_iter()
+        .map(|key| {
+            key.map_or(Ok(K2::default()), |key| {
+                K2::try_from(position(key)).map_err(|_| Error::Overflow)
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let keys = PrimitiveArray::<K2>::new(
+        K2::PRIMITIVE.into(),
+        keys.into(),
+        array.keys().validity().cloned(),
+    );
+
+    let data_type = DataType::Dictionary(
+        K2::KEY_TYPE,
+        Box::new(values.data_type().clone()),
+        is_ordered,
+    );
+    // Safety: every position is smaller than the length of `values`
+    unsafe { DictionaryArray::try_new_unchecked(data_type, keys, values) }
+}
+
+/// Returns `arrays` remapped to share a single dictionary containing the distinct values of
+/// all their dictionaries, sorted in ascending order (with nulls last).
+///
+/// The resulting dictionaries are ordered (see [`DictionaryArray::is_ordered`]).
+/// # Example
+/// ```
+/// use arrow2::array::{DictionaryArray, MutableDictionaryArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::dictionary::unify_dictionaries;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+/// a.try_extend(vec![Some("b"), Some("a")])?;
+/// let a: DictionaryArray<i32> = a.into();
+/// let mut b = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+/// b.try_extend(vec![Some("c"), Some("b")])?;
+/// let b: DictionaryArray<i32> = b.into();
+///
+/// let unified = unify_dictionaries(&[&a, &b])?;
+/// assert_eq!(unified[0].values(), unified[1].values());
+/// assert_eq!(unified[0].keys().values().as_slice(), &[1, 0]);
+/// assert_eq!(unified[1].keys().values().as_slice(), &[2, 1]);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the values of `arrays` have different data types or cannot be sorted,
+/// or the number of distinct values does not fit in `K`.
+pub fn unify_dictionaries<K: DictionaryKey>(
+    arrays: &[&DictionaryArray<K>],
+) -> Result<Vec<DictionaryArray<K>>> {
+    let values = arrays
+        .iter()
+        .map(|x| x.values().as_ref())
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return Ok(vec![]);
+    }
+    if values
+        .iter()
+        .any(|x| x.data_type() != values[0].data_type())
+    {
+        return Err(Error::InvalidArgumentError(
+            "Dictionaries can only be unified when their values have the same data type"
+                .to_string(),
+        ));
+    }
+
+    let capacity = values.iter().map(|x| x.len()).sum();
+    let mut growable = make_growable(&values, false, capacity);
+    values
+        .iter()
+        .enumerate()
+        .for_each(|(index, x)| growable.extend(index, 0, x.len()));
+    let all_values = growable.as_box();
+
+    let (unique, positions) = distinct(all_values.as_ref())?;
+    let unified = take_values(all_values.as_ref(), &unique)?;
+
+    let mut offset = 0;
+    arrays
+        .iter()
+        .map(|array| {
+            let length = array.values().len();
+            let positions = &positions[offset..offset + length];
+            offset += length;
+            remap(array, |key| positions[key], unified.clone(), true)
+        })
+        .collect()
+}
+
+/// Returns `array` without the values that are not referenced by any of its keys.
+///
+/// The order of the remaining values, and thus whether the dictionary is ordered, is preserved.
+/// # Errors
+/// Errors iff the number of remaining values does not fit in `K`.
+pub fn compact<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<DictionaryArray<K>> {
+    let mut is_referenced = vec![false; array.values().len()];
+    array
+        .keys_iter()
+        .flatten()
+        .for_each(|key| is_referenced[key] = true);
+
+    let mut kept = Vec::with_capacity(is_referenced.len());
+    let positions = is_referenced
+        .iter()
+        .enumerate()
+        .map(|(index, is_referenced)| {
+            if *is_referenced {
+                kept.push(index);
+            }
+            // unreferenced values are never looked up
+            kept.len().saturating_sub(1)
+        })
+        .collect::<Vec<_>>();
+
+    let values = take_values(array.values().as_ref(), &kept)?;
+    remap(array, |key| positions[key], values, array.is_ordered())
+}
+
+/// Returns `array` with its values deduplicated and sorted in ascending order (with nulls last).
+///
+/// The resulting dictionary is ordered (see [`DictionaryArray::is_ordered`]), so that its
+/// keys can be compared instead of its values.
+/// # Errors
+/// Errors iff the values of `array` cannot be sorted.
+pub fn sort_dictionary<K: DictionaryKey>(array: &DictionaryArray<K>) -> Result<DictionaryArray<K>> {
+    let (unique, positions) = distinct(array.values().as_ref())?;
+    let values = take_values(array.values().as_ref(), &unique)?;
+    remap(array, |key| positions[key], values, true)
+}
+
+/// Returns `array` with keys of type `K2`, sharing the same values.
+/// # Errors
+/// Errors iff any of the keys of `array` does not fit in `K2`.
+pub fn cast_keys<K1: DictionaryKey, K2: DictionaryKey>(
+    array: &DictionaryArray<K1>,
+) -> Result<DictionaryArray<K2>> {
+    remap(array, |key| key, array.values().clone(), array.is_ordered())
+}
+
+macro_rules! with_match_hashable_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        _ => return Err(Error::InvalidArgumentError(format!(
+            "`dictionary_encode` does not support primitive `{:?}`",
+            $key_type,
+        ))),
+    }
+})}
+
+fn encode<K, M, T, I>(
+    mut array: MutableDictionaryArray<K, M>,
+    iter: I,
+    max_cardinality: usize,
+) -> Result<Option<Box<dyn Array>>>
+where
+    K: DictionaryKey,
+    M: MutableArray + Indexable + TryPush<Option<T>> + 'static,
+    M::Type: Eq + Hash,
+    T: AsIndexed<M>,
+    I: Iterator<Item = Option<T>>,
+{
+    array.reserve(iter.size_hint().0);
+    for value in iter {
+        array.try_push(value)?;
+        if array.values().len() > max_cardinality {
+            return Ok(None);
+        }
+    }
+    Ok(Some(array.into_box()))
+}
+
+fn encode_utf8<K: DictionaryKey, O: Offset>(
+    array: &dyn Array,
+    max_cardinality: usize,
+) -> Result<Option<Box<dyn Array>>> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let dictionary = MutableDictionaryArray::<K, MutableUtf8Array<O>>::new();
+    encode(dictionary, array.iter(), max_cardinality)
+}
+
+fn encode_binary<K: DictionaryKey, O: Offset>(
+    array: &dyn Array,
+    max_cardinality: usize,
+) -> Result<Option<Box<dyn Array>>> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let dictionary = MutableDictionaryArray::<K, MutableBinaryArray<O>>::new();
+    encode(dictionary, array.iter(), max_cardinality)
+}
+
+fn encode_primitive<K: DictionaryKey, T: NativeType + Eq + Hash>(
+    array: &dyn Array,
+    max_cardinality: usize,
+) -> Result<Option<Box<dyn Array>>> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = MutablePrimitiveArray::<T>::new().to(array.data_type().clone());
+    let dictionary = MutableDictionaryArray::<K, _>::try_empty(values)?;
+    encode(
+        dictionary,
+        array.iter().map(|x| x.copied()),
+        max_cardinality,
+    )
+}
+
+fn encode_values<K: DictionaryKey>(
+    array: &dyn Array,
+    max_cardinality: usize,
+) -> Result<Option<Box<dyn Array>>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => encode_utf8::<K, i32>(array, max_cardinality),
+        PhysicalType::LargeUtf8 => encode_utf8::<K, i64>(array, max_cardinality),
+        PhysicalType::Binary => encode_binary::<K, i32>(array, max_cardinality),
+        PhysicalType::LargeBinary => encode_binary::<K, i64>(array, max_cardinality),
+        PhysicalType::Primitive(primitive) => with_match_hashable_primitive_type!(primitive, |$T| {
+            encode_primitive::<K, $T>(array, max_cardinality)
+        }),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "`dictionary_encode` does not support type `{:?}`",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns `array` encoded as a [`DictionaryArray`] with keys of `key_type`, whose values are
+/// the distinct values of `array` in order of first appearance.
+///
+/// Values are deduplicated via hashing, so that each value of `array` is only hashed once.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, DictionaryArray, Utf8Array};
+/// use arrow2::compute::dictionary::dictionary_encode;
+/// use arrow2::datatypes::IntegerType;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a")]);
+/// let result = dictionary_encode(&array, IntegerType::UInt8)?;
+///
+/// let result = result.as_any().downcast_ref::<DictionaryArray<u8>>().unwrap();
+/// assert_eq!(result.values().as_ref(), &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array);
+/// assert_eq!(result.keys().values().as_slice(), &[0, 1, 0, 0]);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the type of `array` is not a string, binary or integer type, or the number of
+/// distinct values does not fit in `key_type`.
+pub fn dictionary_encode(array: &dyn Array, key_type: IntegerType) -> Result<Box<dyn Array>> {
+    dictionary_encode_with_max_cardinality(array, key_type, usize::MAX)
+        .map(|x| x.expect("the cardinality is never above usize::MAX"))
+}
+
+/// Same as [`dictionary_encode`], but aborts and returns `None` as soon as `array` has more
+/// than `max_cardinality` distinct values, i.e. when it is too unique to benefit from
+/// dictionary encoding.
+/// # Errors
+/// Errors iff the type of `array` is not a string, binary or integer type, or the number of
+/// distinct values does not fit in `key_type`.
+pub fn dictionary_encode_with_max_cardinality(
+    array: &dyn Array,
+    key_type: IntegerType,
+    max_cardinality: usize,
+) -> Result<Option<Box<dyn Array>>> {
+    match_integer_type!(key_type, |$K| {
+        encode_values::<$K>(array, max_cardinality)
+    })
+}
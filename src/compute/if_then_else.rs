@@ -1,4 +1,4 @@
-//! Contains the operator [`if_then_else`].
+//! Contains the operators [`if_then_else`], [`case_when`] and [`coalesce`].
 use crate::array::{growable, Array, BooleanArray};
 use crate::bitmap::utils::SlicesIterator;
 use crate::error::{Error, Result};
@@ -73,3 +73,164 @@ pub fn if_then_else(
     };
     Ok(result)
 }
+
+// Checks that all `arrays` have the same data type and a length of either `len` or 1.
+fn check_arrays(arrays: &[&dyn Array], len: usize, operator: &str) -> Result<()> {
+    let data_type = arrays[0].data_type();
+    if let Some(array) = arrays.iter().find(|x| x.data_type() != data_type) {
+        return Err(Error::InvalidArgumentError(format!(
+            "{operator} requires the arguments to have the same datatypes ({:?} != {:?})",
+            data_type,
+            array.data_type()
+        )));
+    }
+    if let Some(array) = arrays.iter().find(|x| x.len() != len && x.len() != 1) {
+        return Err(Error::InvalidArgumentError(format!(
+            "{operator} requires all arguments to have the same length ({len}) or length 1 ({})",
+            array.len()
+        )));
+    }
+    Ok(())
+}
+
+// Builds an array of length `len` whose row `i` is taken from row `i` of `arrays[source(i)]`,
+// or is null when `source(i)` is `None`. Arrays of length 1 are broadcasted.
+// Consecutive rows from the same array are extended in a single call.
+fn build<F>(arrays: &[&dyn Array], len: usize, source: F) -> Box<dyn Array>
+where
+    F: Fn(usize) -> Option<usize>,
+{
+    let mut growable = growable::make_growable(arrays, true, len);
+
+    let mut extend = |source: Option<usize>, start: usize, length: usize| match source {
+        Some(index) if arrays[index].len() == 1 => {
+            (0..length).for_each(|_| growable.extend(index, 0, 1))
+        }
+        Some(index) => growable.extend(index, start, length),
+        None => growable.extend_validity(length),
+    };
+
+    if len > 0 {
+        let mut start = 0;
+        let mut current = source(0);
+        for i in 1..len {
+            let source = source(i);
+            if source != current {
+                extend(current, start, i - start);
+                start = i;
+                current = source;
+            }
+        }
+        extend(current, start, len - start);
+    }
+
+    growable.as_box()
+}
+
+/// Returns, for each row, the first non-null value of `arrays`, or `None` if all are null.
+/// Arrays of length 1 are broadcasted to the length of the other arrays, which allows
+/// using them as scalars.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::if_then_else::coalesce;
+/// use arrow2::array::Int32Array;
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int32Array::from(&[None, Some(5), None]);
+/// let default = Int32Array::from_slice(&[0]);
+/// let result = coalesce(&[&a, &b, &default])?;
+///
+/// let expected = Int32Array::from_slice(&[1, 5, 0]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff `arrays` is empty, the arrays do not have the same data type or their
+/// lengths differ and are not 1.
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    if arrays.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "Coalesce requires at least one argument".to_string(),
+        ));
+    }
+    let len = arrays.iter().map(|x| x.len()).max().unwrap();
+    check_arrays(arrays, len, "Coalesce")?;
+
+    let is_valid = |array: &&dyn Array, i: usize| {
+        if array.len() == 1 {
+            array.is_valid(0)
+        } else {
+            array.is_valid(i)
+        }
+    };
+    Ok(build(arrays, len, |i| {
+        arrays.iter().position(|array| is_valid(array, i))
+    }))
+}
+
+/// Returns, for each row, the value of the first branch whose predicate is `true`, or the
+/// value of `otherwise` if no predicate is `true`. A null predicate is treated as `false`.
+///
+/// All branches are evaluated in a single pass. Values (including `otherwise`) of length 1
+/// are broadcasted to the length of the predicates, which allows using them as scalars.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::if_then_else::case_when;
+/// use arrow2::array::{BooleanArray, Utf8Array};
+///
+/// # fn main() -> Result<()> {
+/// let small = BooleanArray::from_slice(&[true, false, false]);
+/// let medium = BooleanArray::from(&[Some(true), Some(true), None]);
+/// let result = case_when(
+///     &[
+///         (&small, &Utf8Array::<i32>::from_slice(&["small"])),
+///         (&medium, &Utf8Array::<i32>::from_slice(&["medium"])),
+///     ],
+///     &Utf8Array::<i32>::from_slice(&["large"]),
+/// )?;
+///
+/// let expected = Utf8Array::<i32>::from_slice(&["small", "medium", "large"]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff `branches` is empty, the predicates do not have the same length, the values
+/// do not have the same data type or their lengths differ from the predicates' and are not 1.
+pub fn case_when(
+    branches: &[(&BooleanArray, &dyn Array)],
+    otherwise: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    if branches.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "Case when requires at least one branch".to_string(),
+        ));
+    }
+    let len = branches[0].0.len();
+    if let Some((predicate, _)) = branches.iter().find(|(x, _)| x.len() != len) {
+        return Err(Error::InvalidArgumentError(format!(
+            "Case when requires all predicates to have the same length ({len} != {})",
+            predicate.len()
+        )));
+    }
+
+    let arrays = branches
+        .iter()
+        .map(|(_, array)| *array)
+        .chain(std::iter::once(otherwise))
+        .collect::<Vec<_>>();
+    check_arrays(&arrays, len, "Case when")?;
+
+    Ok(build(&arrays, len, |i| {
+        branches
+            .iter()
+            .position(|(predicate, _)| predicate.is_valid(i) && predicate.value(i))
+            .or(Some(branches.len()))
+    }))
+}
@@ -0,0 +1,137 @@
+//! Contains the [`interleave`] kernel to gather rows from multiple arrays.
+use crate::{
+    array::{growable::make_growable, Array, PrimitiveArray, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::PhysicalType,
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    types::NativeType,
+};
+
+fn interleave_validity(arrays: &[&dyn Array], indices: &[(usize, usize)]) -> Option<Bitmap> {
+    if arrays.iter().all(|array| array.null_count() == 0) {
+        return None;
+    }
+    Some(
+        indices
+            .iter()
+            .map(|(array, row)| arrays[*array].is_valid(*row))
+            .collect(),
+    )
+}
+
+fn interleave_primitive<T: NativeType>(
+    arrays: &[&dyn Array],
+    indices: &[(usize, usize)],
+) -> PrimitiveArray<T> {
+    let values = arrays
+        .iter()
+        .map(|array| {
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<T>>()
+                .unwrap()
+                .values()
+        })
+        .collect::<Vec<_>>();
+
+    let values = indices
+        .iter()
+        .map(|(array, row)| values[*array][*row])
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::new(
+        arrays[0].data_type().clone(),
+        values.into(),
+        interleave_validity(arrays, indices),
+    )
+}
+
+fn interleave_utf8<O: Offset>(
+    arrays: &[&dyn Array],
+    indices: &[(usize, usize)],
+) -> Result<Utf8Array<O>> {
+    let utf8 = arrays
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<Utf8Array<O>>().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut offsets = Offsets::<O>::with_capacity(indices.len());
+    let mut values = Vec::<u8>::new();
+    for (array, row) in indices {
+        let value = utf8[*array].value(*row);
+        offsets.try_push_usize(value.len())?;
+        values.extend_from_slice(value.as_bytes());
+    }
+
+    // Safety: `values` is a concatenation of valid utf8 strings delimited by `offsets`
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            arrays[0].data_type().clone(),
+            offsets.into(),
+            values.into(),
+            interleave_validity(arrays, indices),
+        )
+    })
+}
+
+/// Returns a new [`Array`] whose row `i` is the row `indices[i].1` of the array
+/// `arrays[indices[i].0]`.
+///
+/// This is a generalization of [`take`](crate::compute::take::take) to multiple arrays, and
+/// is used to assemble the outputs of e.g. joins and k-way merges. Primitive and utf8
+/// arrays are gathered directly; every other type is gathered via a
+/// [`Growable`](crate::array::growable::Growable).
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::interleave::interleave;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let a = Int32Array::from_slice([1, 2, 3]);
+/// let b = Int32Array::from([Some(4), None]);
+///
+/// let result = interleave(&[&a, &b], &[(1, 0), (0, 2), (1, 1), (0, 0)])?;
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(4), Some(3), None, Some(1)]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff `arrays` is empty, the arrays have different data types or any index is out
+/// of bounds.
+pub fn interleave(arrays: &[&dyn Array], indices: &[(usize, usize)]) -> Result<Box<dyn Array>> {
+    let data_type = arrays
+        .first()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("interleave requires at least one array".to_string())
+        })?
+        .data_type();
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        return Err(Error::InvalidArgumentError(
+            "interleave requires all arrays to have the same data type".to_string(),
+        ));
+    }
+    if let Some((array, row)) = indices
+        .iter()
+        .find(|(array, row)| *array >= arrays.len() || *row >= arrays[*array].len())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "The index ({array}, {row}) is out of bounds"
+        )));
+    }
+
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Ok(interleave_primitive::<$T>(arrays, indices).boxed())
+        }),
+        PhysicalType::Utf8 => Ok(interleave_utf8::<i32>(arrays, indices)?.boxed()),
+        PhysicalType::LargeUtf8 => Ok(interleave_utf8::<i64>(arrays, indices)?.boxed()),
+        _ => {
+            let mut growable = make_growable(arrays, false, indices.len());
+            for (array, row) in indices {
+                growable.extend(*array, *row, 1);
+            }
+            Ok(growable.as_box())
+        }
+    }
+}
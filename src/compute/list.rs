@@ -0,0 +1,502 @@
+//! Contains kernels operating on the contents of [`ListArray`] and [`FixedSizeListArray`].
+//!
+//! All kernels accept a [`ListArray<i32>`], a [`ListArray<i64>`] or a [`FixedSizeListArray`]
+//! as a `&dyn Array` and operate on each of its rows independently.
+use std::cmp::Ordering;
+use std::ops::Add;
+
+use num_traits::AsPrimitive;
+
+use crate::{
+    array::{
+        growable::make_growable, ord::build_compare, Array, FixedSizeListArray, ListArray,
+        PrimitiveArray, UInt64Array,
+    },
+    bitmap::Bitmap,
+    compute::take::take,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    types::NativeType,
+};
+
+macro_rules! with_match_numeric_type {(
+    $key_type:expr, $operator:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => return Err(Error::InvalidArgumentError(format!(
+            "`{}` operator does not support lists of primitive `{:?}`",
+            $operator,
+            $key_type,
+        ))),
+    }
+})}
+
+/// The values of a list array, the range of the values of each of its rows and its validity.
+struct Lists<'a> {
+    values: &'a dyn Array,
+    ranges: Vec<(usize, usize)>,
+    validity: Option<&'a Bitmap>,
+}
+
+impl<'a> Lists<'a> {
+    fn try_new(array: &'a dyn Array, operator: &str) -> Result<Self> {
+        match array.data_type().to_physical_type() {
+            PhysicalType::List => Ok(Self::from_list::<i32>(array)),
+            PhysicalType::LargeList => Ok(Self::from_list::<i64>(array)),
+            PhysicalType::FixedSizeList => {
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                let size = array.size();
+                Ok(Self {
+                    values: array.values().as_ref(),
+                    ranges: (0..array.len())
+                        .map(|i| (i * size, (i + 1) * size))
+                        .collect(),
+                    validity: array.validity(),
+                })
+            }
+            _ => Err(Error::InvalidArgumentError(format!(
+                "`{}` operator does not support type `{:?}`",
+                operator,
+                array.data_type()
+            ))),
+        }
+    }
+
+    fn from_list<O: Offset>(array: &'a dyn Array) -> Self {
+        let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+        let offsets = array.offsets();
+        Self {
+            values: array.values().as_ref(),
+            ranges: (0..array.len()).map(|i| offsets.start_end(i)).collect(),
+            validity: array.validity(),
+        }
+    }
+
+    #[inline]
+    fn is_valid(&self, row: usize) -> bool {
+        self.validity.map(|x| x.get_bit(row)).unwrap_or(true)
+    }
+
+    // the total number of values of all valid rows
+    fn valid_len(&self) -> usize {
+        self.ranges
+            .iter()
+            .enumerate()
+            .filter(|(row, _)| self.is_valid(*row))
+            .map(|(_, (start, end))| end - start)
+            .sum()
+    }
+}
+
+fn try_new_list<O: Offset>(
+    data_type: DataType,
+    values: Box<dyn Array>,
+    lengths: impl Iterator<Item = usize>,
+    validity: Option<Bitmap>,
+) -> Result<Box<dyn Array>> {
+    let offsets = Offsets::<O>::try_from_lengths(lengths)?;
+    Ok(ListArray::<O>::try_new(data_type, offsets.into(), values, validity)?.boxed())
+}
+
+/// Returns a list array of the same logical type as `array` (a [`ListArray<i32>`] when
+/// `array` is a [`FixedSizeListArray`]) whose rows have `lengths` and are backed by `values`.
+fn new_list(
+    array: &dyn Array,
+    values: Box<dyn Array>,
+    lengths: impl Iterator<Item = usize>,
+    validity: Option<Bitmap>,
+) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::LargeList(_) => {
+            try_new_list::<i64>(array.data_type().clone(), values, lengths, validity)
+        }
+        DataType::FixedSizeList(field, _) => {
+            try_new_list::<i32>(DataType::List(field.clone()), values, lengths, validity)
+        }
+        _ => try_new_list::<i32>(array.data_type().clone(), values, lengths, validity),
+    }
+}
+
+/// Returns the values of all valid rows of `array`, alongside the index of the row of each value.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::flatten;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])])?;
+/// let array: ListArray<i32> = array.into();
+///
+/// let (values, parents) = flatten(&array)?;
+/// assert_eq!(values.as_ref(), &Int32Array::from_slice([1, 2, 3]) as &dyn arrow2::array::Array);
+/// assert_eq!(parents.values().as_slice(), &[0, 0, 2]);
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff `array` is not a list array.
+pub fn flatten(array: &dyn Array) -> Result<(Box<dyn Array>, UInt64Array)> {
+    let lists = Lists::try_new(array, "flatten")?;
+    let capacity = lists.valid_len();
+
+    let mut values = make_growable(&[lists.values], false, capacity);
+    let mut parents = Vec::with_capacity(capacity);
+    for (row, (start, end)) in lists.ranges.iter().enumerate() {
+        if lists.is_valid(row) && end > start {
+            values.extend(0, *start, end - start);
+            parents.extend(std::iter::repeat(row as u64).take(end - start));
+        }
+    }
+    Ok((values.as_box(), UInt64Array::from_vec(parents)))
+}
+
+/// Returns the values of all rows of `array`, alongside the index of the row of each value,
+/// where null and empty rows are represented by a single null value.
+///
+/// The indices can be used with [`take`] to repeat the other columns of a table accordingly.
+/// # Error
+/// Errors iff `array` is not a list array.
+pub fn explode(array: &dyn Array) -> Result<(Box<dyn Array>, UInt64Array)> {
+    let lists = Lists::try_new(array, "explode")?;
+    let capacity = lists.valid_len() + lists.ranges.len();
+
+    let mut values = make_growable(&[lists.values], true, capacity);
+    let mut parents = Vec::with_capacity(capacity);
+    for (row, (start, end)) in lists.ranges.iter().enumerate() {
+        if lists.is_valid(row) && end > start {
+            values.extend(0, *start, end - start);
+            parents.extend(std::iter::repeat(row as u64).take(end - start));
+        } else {
+            values.extend_validity(1);
+            parents.push(row as u64);
+        }
+    }
+    Ok((values.as_box(), UInt64Array::from_vec(parents)))
+}
+
+/// Returns the number of values in each row of `array`, as an [`Int64Array`](crate::array::Int64Array)
+/// for [`ListArray<i64>`] and an [`Int32Array`](crate::array::Int32Array) otherwise.
+/// # Error
+/// Errors iff `array` is not a list array.
+pub fn list_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_length")?;
+    let validity = lists.validity.cloned();
+    let lengths = lists.ranges.iter().map(|(start, end)| end - start);
+    Ok(
+        if array.data_type().to_physical_type() == PhysicalType::LargeList {
+            PrimitiveArray::<i64>::new(
+                DataType::Int64,
+                lengths.map(|x| x as i64).collect(),
+                validity,
+            )
+            .boxed()
+        } else {
+            PrimitiveArray::<i32>::new(
+                DataType::Int32,
+                lengths.map(|x| x as i32).collect(),
+                validity,
+            )
+            .boxed()
+        },
+    )
+}
+
+/// Returns the value at `index` of each row of `array`. A negative `index` counts from
+/// the end of each row, e.g. `-1` is the last value of each row.
+///
+/// Rows that are null or that are too short to contain `index` result in a null value.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_get;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])])?;
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_get(&array, -2)?;
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), None, None]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff `array` is not a list array.
+pub fn list_get(array: &dyn Array, index: i64) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_get")?;
+
+    let mut values = make_growable(&[lists.values], true, lists.ranges.len());
+    for (row, (start, end)) in lists.ranges.iter().enumerate() {
+        let length = (end - start) as i64;
+        let index = if index < 0 { length + index } else { index };
+        if lists.is_valid(row) && (0..length).contains(&index) {
+            values.extend(0, start + index as usize, 1);
+        } else {
+            values.extend_validity(1);
+        }
+    }
+    Ok(values.as_box())
+}
+
+/// Returns the values between `start` (inclusive) and `end` (exclusive, defaulting to the end
+/// of the row) of each row of `array`. Negative bounds count from the end of each row, and
+/// bounds outside of a row are clamped to it.
+///
+/// The result has the same type as `array`, except for a [`FixedSizeListArray`], which
+/// results in a [`ListArray<i32>`].
+/// # Error
+/// Errors iff `array` is not a list array.
+pub fn list_slice(array: &dyn Array, start: i64, end: Option<i64>) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_slice")?;
+
+    let resolve = |bound: i64, length: i64| {
+        if bound < 0 {
+            (length + bound).max(0)
+        } else {
+            bound.min(length)
+        }
+    };
+
+    let mut values = make_growable(&[lists.values], false, lists.valid_len());
+    let mut lengths = Vec::with_capacity(lists.ranges.len());
+    for (row, (row_start, row_end)) in lists.ranges.iter().enumerate() {
+        let length = (row_end - row_start) as i64;
+        let start = resolve(start, length);
+        let end = end.map(|end| resolve(end, length)).unwrap_or(length);
+        if lists.is_valid(row) && end > start {
+            let length = (end - start) as usize;
+            values.extend(0, row_start + start as usize, length);
+            lengths.push(length);
+        } else {
+            lengths.push(0);
+        }
+    }
+    new_list(
+        array,
+        values.as_box(),
+        lengths.into_iter(),
+        lists.validity.cloned(),
+    )
+}
+
+fn reduce<T, F>(lists: &Lists, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let values = lists
+        .values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let iter = lists.ranges.iter().enumerate().map(|(row, (start, end))| {
+        if !lists.is_valid(row) {
+            return None;
+        }
+        (*start..*end)
+            .filter(|i| values.is_valid(*i))
+            .map(|i| values.value(i))
+            .reduce(&op)
+    });
+    PrimitiveArray::<T>::from_trusted_len_iter(iter).to(values.data_type().clone())
+}
+
+// the [`PrimitiveType`] of the values of `lists`
+fn primitive_type(lists: &Lists, operator: &str) -> Result<PrimitiveType> {
+    match lists.values.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) => Ok(primitive),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "`{}` operator does not support lists of type `{:?}`",
+            operator,
+            lists.values.data_type()
+        ))),
+    }
+}
+
+// addition that wraps around on overflow, like `compute::aggregate::sum`
+trait WrappingAdd: Copy {
+    fn wrapping_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! wrapping_add {
+    ($op:ident, $($T:ty),*) => {$(
+        impl WrappingAdd for $T {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                self.$op(rhs)
+            }
+        }
+    )*};
+}
+
+wrapping_add!(wrapping_add, i8, i16, i32, i64, u8, u16, u32, u64);
+wrapping_add!(add, f32, f64);
+
+/// Returns the sum of the values of each row of `array`, with the same type as its values.
+///
+/// Nulls are ignored, rows that are null or without valid values result in a null, and
+/// integer sums wrap around on overflow.
+/// # Error
+/// Errors iff `array` is not a list array of numeric values.
+pub fn list_sum(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_sum")?;
+    let primitive = primitive_type(&lists, "list_sum")?;
+    with_match_numeric_type!(primitive, "list_sum", |$T| {
+        Ok(reduce::<$T, _>(&lists, WrappingAdd::wrapping_add).boxed())
+    })
+}
+
+/// Returns the minimum of the values of each row of `array`, with the same type as its values.
+///
+/// Nulls are ignored, and rows that are null or without valid values result in a null.
+/// # Error
+/// Errors iff `array` is not a list array of numeric values.
+pub fn list_min(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_min")?;
+    let primitive = primitive_type(&lists, "list_min")?;
+    with_match_numeric_type!(primitive, "list_min", |$T| {
+        Ok(reduce::<$T, _>(&lists, |a, b| if b < a { b } else { a }).boxed())
+    })
+}
+
+/// Returns the maximum of the values of each row of `array`, with the same type as its values.
+///
+/// Nulls are ignored, and rows that are null or without valid values result in a null.
+/// # Error
+/// Errors iff `array` is not a list array of numeric values.
+pub fn list_max(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_max")?;
+    let primitive = primitive_type(&lists, "list_max")?;
+    with_match_numeric_type!(primitive, "list_max", |$T| {
+        Ok(reduce::<$T, _>(&lists, |a, b| if b > a { b } else { a }).boxed())
+    })
+}
+
+fn mean<T: NativeType + AsPrimitive<f64>>(lists: &Lists) -> PrimitiveArray<f64> {
+    let values = lists
+        .values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let iter = lists.ranges.iter().enumerate().map(|(row, (start, end))| {
+        if !lists.is_valid(row) {
+            return None;
+        }
+        let (sum, count) = (*start..*end)
+            .filter(|i| values.is_valid(*i))
+            .fold((0.0, 0usize), |(sum, count), i| {
+                (sum + values.value(i).as_(), count + 1)
+            });
+        (count > 0).then(|| sum / count as f64)
+    });
+    PrimitiveArray::<f64>::from_trusted_len_iter(iter)
+}
+
+/// Returns the arithmetic mean of the values of each row of `array` as a [`Float64Array`](crate::array::Float64Array).
+///
+/// Nulls are ignored, and rows that are null or without valid values result in a null.
+/// # Error
+/// Errors iff `array` is not a list array of numeric values.
+pub fn list_mean(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_mean")?;
+    let primitive = primitive_type(&lists, "list_mean")?;
+    with_match_numeric_type!(primitive, "list_mean", |$T| {
+        Ok(mean::<$T>(&lists).boxed())
+    })
+}
+
+// Returns the indices of the values of `lists` sorted within each row, with nulls last.
+fn sort_rows(lists: &Lists, descending: bool) -> Result<Vec<Vec<u64>>> {
+    let cmp = build_compare(lists.values, lists.values)?;
+    let values = lists.values;
+    Ok(lists
+        .ranges
+        .iter()
+        .map(|(start, end)| {
+            let (mut valid, nulls): (Vec<_>, Vec<_>) =
+                (*start..*end).partition(|i| values.is_valid(*i));
+            valid.sort_by(|a, b| {
+                let ordering = cmp(*a, *b);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+            valid.into_iter().chain(nulls).map(|i| i as u64).collect()
+        })
+        .collect())
+}
+
+/// Sorts the values of each row of `array`, with nulls last.
+///
+/// The result has the same type as `array`.
+/// # Error
+/// Errors iff `array` is not a list array or its values cannot be sorted.
+pub fn list_sort(array: &dyn Array, descending: bool) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_sort")?;
+    let rows = sort_rows(&lists, descending)?;
+
+    let lengths = rows.iter().map(|row| row.len()).collect::<Vec<_>>();
+    let indices = UInt64Array::from_vec(rows.into_iter().flatten().collect());
+    let values = take(lists.values, &indices)?;
+
+    let validity = lists.validity.cloned();
+    if array.data_type().to_physical_type() == PhysicalType::FixedSizeList {
+        Ok(FixedSizeListArray::try_new(array.data_type().clone(), values, validity)?.boxed())
+    } else {
+        new_list(array, values, lengths.into_iter(), validity)
+    }
+}
+
+/// Returns the distinct values of each row of `array`, sorted in ascending order and with a
+/// single null last if the row contains nulls.
+///
+/// The result has the same type as `array`, except for a [`FixedSizeListArray`], which
+/// results in a [`ListArray<i32>`].
+/// # Error
+/// Errors iff `array` is not a list array or its values cannot be sorted.
+pub fn list_unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array, "list_unique")?;
+    let cmp = build_compare(lists.values, lists.values)?;
+    let rows = sort_rows(&lists, false)?;
+
+    let mut lengths = Vec::with_capacity(rows.len());
+    let mut indices = vec![];
+    for (row, sorted) in rows.into_iter().enumerate() {
+        let start = indices.len();
+        if lists.is_valid(row) {
+            let mut has_null = false;
+            for i in sorted {
+                if !lists.values.is_valid(i as usize) {
+                    if !has_null {
+                        indices.push(i);
+                        has_null = true;
+                    }
+                } else if indices.len() == start
+                    || cmp(indices[indices.len() - 1] as usize, i as usize) != Ordering::Equal
+                {
+                    indices.push(i);
+                }
+            }
+        }
+        lengths.push(indices.len() - start);
+    }
+
+    let values = take(lists.values, &UInt64Array::from_vec(indices))?;
+    new_list(array, values, lengths.into_iter(), lists.validity.cloned())
+}
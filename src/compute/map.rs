@@ -0,0 +1,237 @@
+//! Contains kernels to access the keys and values of [`MapArray`]s.
+//!
+//! Key lookups are supported for maps whose keys are strings or integers.
+use crate::{
+    array::{
+        growable::make_growable, Array, BooleanArray, ListArray, MapArray, PrimitiveArray,
+        StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    scalar::{PrimitiveScalar, Scalar, Utf8Scalar},
+    types::NativeType,
+};
+
+use super::utils::combine_validities;
+
+macro_rules! with_match_integer_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        _ => return Err(Error::InvalidArgumentError(format!(
+            "Looking up keys of maps is not supported for keys of primitive `{:?}`",
+            $key_type,
+        ))),
+    }
+})}
+
+fn entries(map: &MapArray) -> &StructArray {
+    // the entries of a map are guaranteed to be a struct of keys and values
+    map.field().as_any().downcast_ref::<StructArray>().unwrap()
+}
+
+/// Returns, for each valid row of `map`, the position in its entries of the first key
+/// fulfilling `eq`.
+fn positions<F: Fn(usize) -> bool>(map: &MapArray, keys: &dyn Array, eq: F) -> Vec<Option<usize>> {
+    (0..map.len())
+        .map(|row| {
+            if !map.is_valid(row) {
+                return None;
+            }
+            let (start, end) = map.offsets().start_end(row);
+            (start..end).find(|i| keys.is_valid(*i) && eq(*i))
+        })
+        .collect()
+}
+
+fn positions_utf8<O: Offset>(
+    map: &MapArray,
+    keys: &dyn Array,
+    key: &dyn Scalar,
+) -> Vec<Option<usize>> {
+    let key = key.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    let key = key.value().unwrap();
+    let keys = keys.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    positions(map, keys, |i| keys.value(i) == key)
+}
+
+fn positions_primitive<T: NativeType>(
+    map: &MapArray,
+    keys: &dyn Array,
+    key: &dyn Scalar,
+) -> Vec<Option<usize>> {
+    let key = key.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    let key = key.value().unwrap();
+    let keys = keys.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    positions(map, keys, |i| keys.value(i) == key)
+}
+
+/// Returns, for each row of `map`, the position in its entries of `key`, if any.
+fn find(map: &MapArray, key: &dyn Scalar) -> Result<Vec<Option<usize>>> {
+    let keys = entries(map).values()[0].as_ref();
+    if keys.data_type() != key.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The key must have the same type as the keys of the map, `{:?}`, but has type `{:?}`",
+            keys.data_type(),
+            key.data_type()
+        )));
+    }
+    if !key.is_valid() {
+        return Ok(vec![None; map.len()]);
+    }
+
+    Ok(match keys.data_type().to_physical_type() {
+        PhysicalType::Utf8 => positions_utf8::<i32>(map, keys, key),
+        PhysicalType::LargeUtf8 => positions_utf8::<i64>(map, keys, key),
+        PhysicalType::Primitive(primitive) => with_match_integer_type!(primitive, |$T| {
+            positions_primitive::<$T>(map, keys, key)
+        }),
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "Looking up keys of maps is not supported for keys of type `{:?}`",
+                keys.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns the value of `key` in each row of `map`, or null if the row is null or does not
+/// contain `key`. When a row contains `key` more than once, its first value is returned.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, MapArray, MutableMapArray, MutablePrimitiveArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::map::map_get;
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let mut map = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+/// map.try_extend(vec![
+///     Some(vec![(Some("env"), Some(1)), (Some("app"), Some(2))]),
+///     None,
+///     Some(vec![(Some("app"), Some(3))]),
+/// ])?;
+/// let map: MapArray = map.into();
+///
+/// let result = map_get(&map, &Utf8Scalar::<i32>::from(Some("env")))?;
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), None, None]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the type of `key` differs from the type of the keys of `map`, or the keys
+/// are neither strings nor integers.
+pub fn map_get(map: &MapArray, key: &dyn Scalar) -> Result<Box<dyn Array>> {
+    let positions = find(map, key)?;
+
+    let values = entries(map).values()[1].as_ref();
+    let mut growable = make_growable(&[values], true, map.len());
+    for position in positions {
+        match position {
+            Some(position) => growable.extend(0, position, 1),
+            None => growable.extend_validity(1),
+        }
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns whether each row of `map` contains `key`, or null if the row or `key` is null.
+/// # Errors
+/// Errors iff the type of `key` differs from the type of the keys of `map`, or the keys
+/// are neither strings nor integers.
+pub fn map_contains_key(map: &MapArray, key: &dyn Scalar) -> Result<BooleanArray> {
+    let positions = find(map, key)?;
+    if !key.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, map.len()));
+    }
+
+    let values = positions.iter().map(|x| x.is_some()).collect::<Bitmap>();
+    Ok(BooleanArray::new(
+        DataType::Boolean,
+        values,
+        map.validity().cloned(),
+    ))
+}
+
+fn entries_to_list(map: &MapArray, index: usize) -> ListArray<i32> {
+    let entries = entries(map);
+    let field = entries.fields()[index].clone();
+    ListArray::new(
+        DataType::List(Box::new(field)),
+        map.offsets().clone(),
+        entries.values()[index].clone(),
+        map.validity().cloned(),
+    )
+}
+
+/// Returns the keys of each row of `map` as a [`ListArray`].
+pub fn map_keys(map: &MapArray) -> ListArray<i32> {
+    entries_to_list(map, 0)
+}
+
+/// Returns the values of each row of `map` as a [`ListArray`].
+pub fn map_values(map: &MapArray) -> ListArray<i32> {
+    entries_to_list(map, 1)
+}
+
+/// Returns a [`MapArray`] whose rows are composed by the rows of `keys` and `values`.
+/// A row is null if it is null in either `keys` or `values`.
+///
+/// The resulting [`DataType`] is [`MapArray::default_datatype`].
+/// # Errors
+/// Errors iff:
+/// * `keys` and `values` have a different number of rows
+/// * any row of `keys` has a different number of entries than its row of `values`
+/// * any of the keys is null
+pub fn map_from_arrays(keys: &ListArray<i32>, values: &ListArray<i32>) -> Result<MapArray> {
+    if keys.len() != values.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The keys and values of a map must have the same number of rows, but have {} and {}",
+            keys.len(),
+            values.len()
+        )));
+    }
+    if keys.offsets().lengths().ne(values.offsets().lengths()) {
+        return Err(Error::InvalidArgumentError(
+            "Every row of the keys of a map must have the same number of entries as its values"
+                .to_string(),
+        ));
+    }
+
+    let length = keys.offsets().range() as usize;
+    let keys_values = keys
+        .values()
+        .sliced(*keys.offsets().first() as usize, length);
+    let values_values = values
+        .values()
+        .sliced(*values.offsets().first() as usize, length);
+    if keys_values.null_count() > 0 {
+        return Err(Error::InvalidArgumentError(
+            "The keys of a map must not be null".to_string(),
+        ));
+    }
+
+    let data_type = MapArray::default_datatype(
+        keys_values.data_type().clone(),
+        values_values.data_type().clone(),
+    );
+    let entries = StructArray::try_new(
+        MapArray::get_field(&data_type).data_type().clone(),
+        vec![keys_values, values_values],
+        None,
+    )?;
+    let offsets = Offsets::<i32>::try_from_lengths(keys.offsets().lengths())?;
+    let validity = combine_validities(keys.validity(), values.validity());
+    MapArray::try_new(data_type, offsets.into(), entries.boxed(), validity)
+}
@@ -40,6 +40,9 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_dictionary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_dictionary")))]
+pub mod dictionary;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
@@ -49,6 +52,9 @@ pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_interleave")))]
+pub mod interleave;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -58,6 +64,12 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
+#[cfg(feature = "compute_map")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_map")))]
+pub mod map;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
@@ -70,9 +82,18 @@ pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_repeat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_repeat")))]
+pub mod repeat;
+#[cfg(feature = "compute_selection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_selection")))]
+pub mod selection;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_struct")))]
+pub mod struct_;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
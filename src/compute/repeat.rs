@@ -0,0 +1,238 @@
+//! Contains kernels to materialize [`Scalar`]s into arrays and to repeat the slots of arrays.
+use crate::{
+    array::{
+        growable::make_growable, new_null_array, Array, BinaryArray, BooleanArray, DictionaryArray,
+        DictionaryKey, FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, NullArray,
+        PrimitiveArray, StructArray, UInt64Array, UnionArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    scalar::*,
+    types::NativeType,
+};
+
+use super::take::take;
+
+fn repeat_offsets<O: Offset>(length: usize, n: usize) -> Result<Offsets<O>> {
+    Offsets::try_from_lengths(std::iter::repeat(length).take(n))
+}
+
+// `values` concatenated `n` times
+fn repeat_values(values: &dyn Array, n: usize) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], false, values.len() * n);
+    (0..n).for_each(|_| growable.extend(0, 0, values.len()));
+    growable.as_box()
+}
+
+fn repeat_primitive<T: NativeType>(scalar: &dyn Scalar, n: usize) -> Box<dyn Array> {
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap();
+    let value = scalar.value().unwrap();
+    PrimitiveArray::<T>::new(scalar.data_type().clone(), vec![value; n].into(), None).boxed()
+}
+
+fn repeat_utf8<O: Offset>(scalar: &dyn Scalar, n: usize) -> Result<Box<dyn Array>> {
+    let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    let value = scalar.value().unwrap();
+    Ok(Utf8Array::<O>::try_new(
+        scalar.data_type().clone(),
+        repeat_offsets(value.len(), n)?.into(),
+        value.as_bytes().repeat(n).into(),
+        None,
+    )?
+    .boxed())
+}
+
+fn repeat_binary<O: Offset>(scalar: &dyn Scalar, n: usize) -> Result<Box<dyn Array>> {
+    let scalar = scalar.as_any().downcast_ref::<BinaryScalar<O>>().unwrap();
+    let value = scalar.value().unwrap();
+    Ok(BinaryArray::<O>::try_new(
+        scalar.data_type().clone(),
+        repeat_offsets(value.len(), n)?.into(),
+        value.repeat(n).into(),
+        None,
+    )?
+    .boxed())
+}
+
+fn repeat_list<O: Offset>(scalar: &dyn Scalar, n: usize) -> Result<Box<dyn Array>> {
+    let scalar = scalar.as_any().downcast_ref::<ListScalar<O>>().unwrap();
+    let values = scalar.values();
+    Ok(ListArray::<O>::try_new(
+        scalar.data_type().clone(),
+        repeat_offsets(values.len(), n)?.into(),
+        repeat_values(values.as_ref(), n),
+        None,
+    )?
+    .boxed())
+}
+
+fn repeat_dictionary<K: DictionaryKey>(scalar: &dyn Scalar, n: usize) -> Result<Box<dyn Array>> {
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<DictionaryScalar<K>>()
+        .unwrap();
+    let values = repeat(scalar.value().unwrap().as_ref(), 1)?;
+    let keys = PrimitiveArray::<K>::from_vec(vec![K::default(); n]);
+    Ok(DictionaryArray::<K>::try_new(scalar.data_type().clone(), keys, values)?.boxed())
+}
+
+fn repeat_union(scalar: &dyn Scalar, n: usize) -> Result<Box<dyn Array>> {
+    let scalar = scalar.as_any().downcast_ref::<UnionScalar>().unwrap();
+    let data_type = scalar.data_type();
+    let (fields, ids, mode) = match data_type.to_logical_type() {
+        DataType::Union(fields, ids, mode) => (fields, ids, mode),
+        _ => unreachable!(),
+    };
+    let index = match ids {
+        Some(ids) => ids.iter().position(|id| *id == scalar.type_() as i32),
+        None => Some(scalar.type_() as usize).filter(|index| *index < fields.len()),
+    }
+    .ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "The type id {} is not part of the union",
+            scalar.type_()
+        ))
+    })?;
+
+    let arrays = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            if i == index {
+                repeat(scalar.value().as_ref(), n)
+            } else if mode.is_sparse() {
+                Ok(new_null_array(field.data_type().clone(), n))
+            } else {
+                Ok(new_null_array(field.data_type().clone(), 0))
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let offsets = (!mode.is_sparse()).then(|| (0..n as i32).collect::<Vec<_>>().into());
+
+    Ok(UnionArray::try_new(
+        data_type.clone(),
+        vec![scalar.type_(); n].into(),
+        arrays,
+        offsets,
+    )?
+    .boxed())
+}
+
+/// Returns an [`Array`] of length `n` whose slots are all equal to `scalar`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::repeat::repeat;
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let result = repeat(&Utf8Scalar::<i32>::from(Some("a")), 3)?;
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from_slice(["a", "a", "a"]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the values of `scalar` do not fit in an array of length `n` (e.g. a string
+/// repeated beyond the capacity of `i32` offsets) or a union `scalar` has an invalid type id.
+pub fn repeat(scalar: &dyn Scalar, n: usize) -> Result<Box<dyn Array>> {
+    let data_type = scalar.data_type();
+    if !scalar.is_valid() {
+        return Ok(new_null_array(data_type.clone(), n));
+    }
+
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Null => Ok(NullArray::new(data_type.clone(), n).boxed()),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let values = Bitmap::new_constant(scalar.value().unwrap(), n);
+            Ok(BooleanArray::new(data_type.clone(), values, None).boxed())
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Ok(repeat_primitive::<$T>(scalar, n))
+        }),
+        Utf8 => repeat_utf8::<i32>(scalar, n),
+        LargeUtf8 => repeat_utf8::<i64>(scalar, n),
+        Binary => repeat_binary::<i32>(scalar, n),
+        LargeBinary => repeat_binary::<i64>(scalar, n),
+        FixedSizeBinary => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            let values = scalar.value().unwrap().repeat(n);
+            Ok(FixedSizeBinaryArray::try_new(data_type.clone(), values.into(), None)?.boxed())
+        }
+        List => repeat_list::<i32>(scalar, n),
+        LargeList => repeat_list::<i64>(scalar, n),
+        FixedSizeList => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeListScalar>()
+                .unwrap();
+            let values = repeat_values(scalar.values().unwrap().as_ref(), n);
+            Ok(FixedSizeListArray::try_new(data_type.clone(), values, None)?.boxed())
+        }
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            let values = scalar
+                .values()
+                .iter()
+                .map(|value| repeat(value.as_ref(), n))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(StructArray::try_new(data_type.clone(), values, None)?.boxed())
+        }
+        Map => {
+            let scalar = scalar.as_any().downcast_ref::<MapScalar>().unwrap();
+            let entries = scalar.values();
+            Ok(MapArray::try_new(
+                data_type.clone(),
+                repeat_offsets(entries.len(), n)?.into(),
+                repeat_values(entries.as_ref(), n),
+                None,
+            )?
+            .boxed())
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            repeat_dictionary::<$T>(scalar, n)
+        }),
+        Union => repeat_union(scalar, n),
+    }
+}
+
+/// Returns an [`Array`] where each slot `i` of `array` is repeated `counts[i]` times.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::repeat::repeat_each;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Int32Array::from([Some(1), None, Some(3)]);
+/// let result = repeat_each(&array, &[2, 1, 0])?;
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), Some(1), None]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff `counts` has a different length than `array`.
+pub fn repeat_each(array: &dyn Array, counts: &[usize]) -> Result<Box<dyn Array>> {
+    if counts.len() != array.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "repeat_each requires one count per slot ({}), but got {} counts",
+            array.len(),
+            counts.len()
+        )));
+    }
+
+    let indices = counts
+        .iter()
+        .enumerate()
+        .flat_map(|(index, count)| std::iter::repeat(index as u64).take(*count))
+        .collect::<Vec<_>>();
+    take(array, &UInt64Array::from_vec(indices))
+}
@@ -0,0 +1,297 @@
+//! Contains [`SelectionVector`], a set of selected rows that can be combined with other
+//! selections before being applied to arrays and [`Chunk`]s.
+use crate::{
+    array::{Array, BooleanArray, UInt64Array},
+    bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap},
+    buffer::Buffer,
+    chunk::Chunk,
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+use super::filter::{build_filter, filter};
+use super::take::take;
+
+/// Selections with a selectivity below this value are applied via [`take`], since gathering
+/// a few rows is cheaper than copying the slices of a mask.
+const TAKE_MAX_SELECTIVITY: f64 = 0.05;
+
+#[derive(Debug, Clone)]
+enum Selection {
+    Mask(Bitmap),
+    Indices(Buffer<u64>),
+}
+
+/// A set of selected rows out of `num_rows` rows, represented either as a mask or as sorted
+/// indices.
+///
+/// Selections can be intersected via [`SelectionVector::and`] and united via
+/// [`SelectionVector::or`] without touching the data they select from. When applied, the
+/// selection uses [`filter`] or [`take`] depending on its selectivity.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, BooleanArray, Int32Array};
+/// use arrow2::compute::selection::SelectionVector;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let array = Int32Array::from_slice([1, 2, 3, 4, 5]);
+/// let a = SelectionVector::from_mask(&BooleanArray::from_slice([true, true, false, true, true]));
+/// let b = SelectionVector::try_from_indices(vec![1, 2, 3], 5)?;
+///
+/// let result = a.and(&b)?.apply(&array)?;
+/// assert_eq!(result.as_ref(), &Int32Array::from_slice([2, 4]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SelectionVector {
+    selection: Selection,
+    num_rows: usize,
+}
+
+fn indices_to_mask(indices: &[u64], num_rows: usize) -> Bitmap {
+    let mut mask = MutableBitmap::from_len_zeroed(num_rows);
+    indices
+        .iter()
+        .for_each(|index| mask.set(*index as usize, true));
+    mask.into()
+}
+
+fn mask_to_indices(mask: &Bitmap) -> Buffer<u64> {
+    let iter = SlicesIterator::new(mask);
+    let mut indices = Vec::with_capacity(iter.slots());
+    iter.for_each(|(start, len)| indices.extend((start..start + len).map(|index| index as u64)));
+    indices.into()
+}
+
+// the sorted indices contained in both `lhs` and `rhs`
+fn intersect(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    let mut result = Vec::with_capacity(lhs.len().min(rhs.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < lhs.len() && j < rhs.len() {
+        match lhs[i].cmp(&rhs[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                result.push(lhs[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    result
+}
+
+// the sorted indices contained in either `lhs` or `rhs`
+fn unite(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+    let mut result = Vec::with_capacity(lhs.len() + rhs.len());
+    let (mut i, mut j) = (0, 0);
+    while i < lhs.len() && j < rhs.len() {
+        match lhs[i].cmp(&rhs[j]) {
+            std::cmp::Ordering::Less => {
+                result.push(lhs[i]);
+                i += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                result.push(rhs[j]);
+                j += 1;
+            }
+            std::cmp::Ordering::Equal => {
+                result.push(lhs[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    result.extend_from_slice(&lhs[i..]);
+    result.extend_from_slice(&rhs[j..]);
+    result
+}
+
+impl SelectionVector {
+    /// Returns a [`SelectionVector`] selecting the rows where `mask` is `true`.
+    /// Null slots of `mask` are not selected.
+    pub fn from_mask(mask: &BooleanArray) -> Self {
+        let mask = match mask.validity() {
+            Some(validity) => mask.values() & validity,
+            None => mask.values().clone(),
+        };
+        Self {
+            num_rows: mask.len(),
+            selection: Selection::Mask(mask),
+        }
+    }
+
+    /// Returns a [`SelectionVector`] selecting the rows at `indices` out of `num_rows` rows.
+    /// # Errors
+    /// Errors iff `indices` is not strictly increasing or any index is not smaller than
+    /// `num_rows`.
+    pub fn try_from_indices(indices: Vec<u64>, num_rows: usize) -> Result<Self> {
+        if indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(Error::InvalidArgumentError(
+                "The indices of a selection must be strictly increasing".to_string(),
+            ));
+        }
+        if let Some(last) = indices.last() {
+            if *last as usize >= num_rows {
+                return Err(Error::InvalidArgumentError(format!(
+                    "The index {last} of the selection is out of bounds for {num_rows} rows"
+                )));
+            }
+        }
+        Ok(Self {
+            selection: Selection::Indices(indices.into()),
+            num_rows,
+        })
+    }
+
+    /// Returns the number of rows this selection selects from.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the number of selected rows.
+    pub fn selected(&self) -> usize {
+        match &self.selection {
+            Selection::Mask(mask) => mask.len() - mask.unset_bits(),
+            Selection::Indices(indices) => indices.len(),
+        }
+    }
+
+    /// Returns the fraction of rows that are selected, or `0.0` when there are no rows.
+    pub fn selectivity(&self) -> f64 {
+        if self.num_rows == 0 {
+            0.0
+        } else {
+            self.selected() as f64 / self.num_rows as f64
+        }
+    }
+
+    fn check_num_rows(&self, other: &Self) -> Result<()> {
+        if self.num_rows != other.num_rows {
+            return Err(Error::InvalidArgumentError(format!(
+                "Selections must select from the same number of rows, but select from {} and {}",
+                self.num_rows, other.num_rows
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the rows selected by both `self` and `other`.
+    /// # Errors
+    /// Errors iff `self` and `other` select from a different number of rows.
+    pub fn and(&self, other: &Self) -> Result<Self> {
+        self.check_num_rows(other)?;
+        let selection = match (&self.selection, &other.selection) {
+            (Selection::Mask(lhs), Selection::Mask(rhs)) => Selection::Mask(lhs & rhs),
+            (Selection::Indices(lhs), Selection::Indices(rhs)) => {
+                Selection::Indices(intersect(lhs, rhs).into())
+            }
+            (Selection::Mask(mask), Selection::Indices(indices))
+            | (Selection::Indices(indices), Selection::Mask(mask)) => Selection::Indices(
+                indices
+                    .iter()
+                    .copied()
+                    .filter(|index| mask.get_bit(*index as usize))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+        };
+        Ok(Self {
+            selection,
+            num_rows: self.num_rows,
+        })
+    }
+
+    /// Returns the rows selected by either `self` or `other`.
+    /// # Errors
+    /// Errors iff `self` and `other` select from a different number of rows.
+    pub fn or(&self, other: &Self) -> Result<Self> {
+        self.check_num_rows(other)?;
+        let selection = match (&self.selection, &other.selection) {
+            (Selection::Mask(lhs), Selection::Mask(rhs)) => Selection::Mask(lhs | rhs),
+            (Selection::Indices(lhs), Selection::Indices(rhs)) => {
+                Selection::Indices(unite(lhs, rhs).into())
+            }
+            (Selection::Mask(mask), Selection::Indices(indices))
+            | (Selection::Indices(indices), Selection::Mask(mask)) => {
+                Selection::Mask(mask | &indices_to_mask(indices, self.num_rows))
+            }
+        };
+        Ok(Self {
+            selection,
+            num_rows: self.num_rows,
+        })
+    }
+
+    /// Returns this selection as a mask of `num_rows` slots without nulls.
+    pub fn to_mask(&self) -> BooleanArray {
+        let mask = match &self.selection {
+            Selection::Mask(mask) => mask.clone(),
+            Selection::Indices(indices) => indices_to_mask(indices, self.num_rows),
+        };
+        BooleanArray::new(DataType::Boolean, mask, None)
+    }
+
+    /// Returns the sorted indices of the selected rows.
+    pub fn to_indices(&self) -> UInt64Array {
+        let indices = match &self.selection {
+            Selection::Mask(mask) => mask_to_indices(mask),
+            Selection::Indices(indices) => indices.clone(),
+        };
+        UInt64Array::new(DataType::UInt64, indices, None)
+    }
+
+    fn use_take(&self) -> bool {
+        self.selectivity() < TAKE_MAX_SELECTIVITY
+    }
+
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.num_rows {
+            return Err(Error::InvalidArgumentError(format!(
+                "The selection selects from {} rows, but the array has {len}",
+                self.num_rows
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the selected rows of `array`.
+    /// # Errors
+    /// Errors iff the length of `array` differs from [`SelectionVector::num_rows`].
+    pub fn apply(&self, array: &dyn Array) -> Result<Box<dyn Array>> {
+        self.check_len(array.len())?;
+        if self.use_take() {
+            take(array, &self.to_indices())
+        } else {
+            filter(array, &self.to_mask())
+        }
+    }
+
+    /// Returns a new [`Chunk`] with the selected rows of each array of `chunk`.
+    /// # Errors
+    /// Errors iff the length of `chunk` differs from [`SelectionVector::num_rows`].
+    pub fn apply_chunk<A: AsRef<dyn Array>>(
+        &self,
+        chunk: &Chunk<A>,
+    ) -> Result<Chunk<Box<dyn Array>>> {
+        self.check_len(chunk.len())?;
+        let arrays = if self.use_take() {
+            let indices = self.to_indices();
+            chunk
+                .arrays()
+                .iter()
+                .map(|array| take(array.as_ref(), &indices))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            let mask = self.to_mask();
+            let filter = build_filter(&mask)?;
+            chunk
+                .arrays()
+                .iter()
+                .map(|array| filter(array.as_ref()))
+                .collect()
+        };
+        Chunk::try_new(arrays)
+    }
+}
@@ -0,0 +1,174 @@
+//! Contains kernels to extract and restructure the fields of [`StructArray`]s.
+use crate::{
+    array::{Array, StructArray},
+    bitmap::Bitmap,
+    chunk::Chunk,
+    datatypes::{DataType, Field, PhysicalType},
+    error::{Error, Result},
+};
+
+use super::utils::combine_validities;
+
+fn as_struct<'a>(array: &'a dyn Array, path: &[&str]) -> Result<&'a StructArray> {
+    array.as_any().downcast_ref::<StructArray>().ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "The field path {path:?} does not resolve to a struct, but to type `{:?}`",
+            array.data_type()
+        ))
+    })
+}
+
+// `values` with the nulls of `validity`. Null and union arrays have no validity and are
+// returned as is.
+fn with_parent_validity(values: &dyn Array, validity: Option<&Bitmap>) -> Box<dyn Array> {
+    match values.data_type().to_physical_type() {
+        PhysicalType::Null | PhysicalType::Union => values.to_boxed(),
+        _ => values.with_validity(combine_validities(validity, values.validity())),
+    }
+}
+
+fn position(array: &StructArray, name: &str) -> Result<usize> {
+    array
+        .fields()
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!("The struct has no field named \"{name}\""))
+        })
+}
+
+/// Returns the field at `path` of `array`, where each element of `path` is the name of a
+/// field of a (nested) [`StructArray`]. A slot of the result is null whenever the slot of
+/// itself or of any of its parents is null, except for null and union fields, which
+/// are returned as is.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, StructArray};
+/// use arrow2::bitmap::Bitmap;
+/// use arrow2::compute::struct_::get_field_path;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let c = Int32Array::from([Some(1), None, Some(3)]);
+/// let b = StructArray::new(
+///     DataType::Struct(vec![Field::new("c", DataType::Int32, true)]),
+///     vec![c.boxed()],
+///     None,
+/// );
+/// let a = StructArray::new(
+///     DataType::Struct(vec![Field::new("b", b.data_type().clone(), true)]),
+///     vec![b.boxed()],
+///     Some(Bitmap::from([true, true, false])),
+/// );
+///
+/// let result = get_field_path(&a, &["b", "c"])?;
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), None, None]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff `path` is empty or does not resolve to a field of `array`.
+pub fn get_field_path(array: &dyn Array, path: &[&str]) -> Result<Box<dyn Array>> {
+    if path.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "The field path must not be empty".to_string(),
+        ));
+    }
+
+    let mut current = array;
+    let mut validity = None;
+    for (depth, name) in path.iter().enumerate() {
+        let array = as_struct(current, &path[..depth])?;
+        validity = combine_validities(validity.as_ref(), array.validity());
+        current = array.values()[position(array, name)?].as_ref();
+    }
+
+    Ok(with_parent_validity(current, validity.as_ref()))
+}
+
+/// Returns a new [`StructArray`] with `field` set to `values`. If `array` has a field of the
+/// same name it is replaced, otherwise `field` is appended as the last field.
+/// # Errors
+/// Errors iff `values` has a different length than `array` or its data type differs from the
+/// data type of `field`.
+pub fn with_field(
+    array: &StructArray,
+    field: Field,
+    values: Box<dyn Array>,
+) -> Result<StructArray> {
+    if values.len() != array.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The values of field \"{}\" must have the same length as the struct ({}), but have {}",
+            field.name,
+            array.len(),
+            values.len()
+        )));
+    }
+
+    let mut fields = array.fields().to_vec();
+    let mut arrays = array.values().to_vec();
+    match position(array, &field.name) {
+        Ok(index) => {
+            fields[index] = field;
+            arrays[index] = values;
+        }
+        Err(_) => {
+            fields.push(field);
+            arrays.push(values);
+        }
+    }
+    StructArray::try_new(DataType::Struct(fields), arrays, array.validity().cloned())
+}
+
+/// Returns a new [`StructArray`] without the field named `name`.
+/// # Errors
+/// Errors iff `array` has no field named `name` or it is its only field.
+pub fn drop_field(array: &StructArray, name: &str) -> Result<StructArray> {
+    let index = position(array, name)?;
+
+    let mut fields = array.fields().to_vec();
+    let mut arrays = array.values().to_vec();
+    fields.remove(index);
+    arrays.remove(index);
+    StructArray::try_new(DataType::Struct(fields), arrays, array.validity().cloned())
+}
+
+/// Returns a new [`StructArray`] whose fields are renamed to `names`, in order.
+/// # Errors
+/// Errors iff the number of `names` differs from the number of fields of `array`.
+pub fn rename_fields(array: &StructArray, names: &[&str]) -> Result<StructArray> {
+    if names.len() != array.fields().len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Expected {} names to rename the fields of the struct, but got {}",
+            array.fields().len(),
+            names.len()
+        )));
+    }
+
+    let fields = array
+        .fields()
+        .iter()
+        .zip(names)
+        .map(|(field, name)| Field {
+            name: name.to_string(),
+            ..field.clone()
+        })
+        .collect();
+    StructArray::try_new(
+        DataType::Struct(fields),
+        array.values().to_vec(),
+        array.validity().cloned(),
+    )
+}
+
+/// Splits `array` into a [`Chunk`] with one column per field, in the order of
+/// [`StructArray::fields`]. A slot of a column is null whenever the slot itself or its
+/// parent slot is null, except for null and union fields, which are returned as is.
+pub fn unnest(array: &StructArray) -> Chunk<Box<dyn Array>> {
+    let arrays = array
+        .values()
+        .iter()
+        .map(|values| with_parent_validity(values.as_ref(), array.validity()))
+        .collect();
+    Chunk::new(arrays)
+}
@@ -0,0 +1,39 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
+
+use super::structure::take_validity;
+use super::Index;
+
+/// `take` implementation for FixedSizeBinaryArrays
+pub fn take<O: Index>(
+    values: &FixedSizeBinaryArray,
+    indices: &PrimitiveArray<O>,
+) -> FixedSizeBinaryArray {
+    let size = values.size();
+    let mut buffer = Vec::<u8>::with_capacity(indices.len() * size);
+    for index in indices.iter() {
+        match index {
+            Some(index) => buffer.extend_from_slice(values.value(index.to_usize())),
+            None => buffer.resize(buffer.len() + size, 0),
+        }
+    }
+
+    // `take_validity` is infallible
+    let validity = take_validity(values.validity(), indices).unwrap();
+    FixedSizeBinaryArray::new(values.data_type().clone(), buffer.into(), validity)
+}
@@ -27,11 +27,14 @@ use crate::{
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_binary;
 mod fixed_size_list;
 mod generic_binary;
 mod list;
+mod map;
 mod primitive;
 mod structure;
+mod union;
 mod utf8;
 
 pub(crate) use boolean::take as take_boolean;
@@ -95,7 +98,18 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(fixed_size_list::take::<O>(array, indices)))
         }
-        t => unimplemented!("Take not supported for data type {:?}", t),
+        FixedSizeBinary => {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_binary::take::<O>(values, indices)))
+        }
+        Map => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(map::take::<O>(array, indices)))
+        }
+        Union => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(union::take::<O>(array, indices)?))
+        }
     }
 }
 
@@ -137,10 +151,13 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::LargeUtf8
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
             | DataType::Struct(_)
             | DataType::List(_)
             | DataType::LargeList(_)
             | DataType::FixedSizeList(_, _)
             | DataType::Dictionary(..)
+            | DataType::Map(..)
+            | DataType::Union(..)
     )
 }
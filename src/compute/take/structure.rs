@@ -24,7 +24,7 @@ use crate::{
 use super::Index;
 
 #[inline]
-fn take_validity<I: Index>(
+pub(super) fn take_validity<I: Index>(
     validity: Option<&Bitmap>,
     indices: &PrimitiveArray<I>,
 ) -> Result<Option<Bitmap>> {
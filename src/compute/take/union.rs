@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::{
+    array::{Array, PrimitiveArray, UnionArray},
+    datatypes::DataType,
+    error::Result,
+};
+
+use super::Index;
+
+/// `take` implementation for UnionArrays. Null indices are taken from the first field.
+pub fn take<O: Index>(values: &UnionArray, indices: &PrimitiveArray<O>) -> Result<UnionArray> {
+    let null_type = match values.data_type().to_logical_type() {
+        DataType::Union(_, ids, _) => ids.as_ref().map(|ids| ids[0] as i8).unwrap_or(0),
+        _ => unreachable!(),
+    };
+    let number_of_fields = values.fields().len();
+
+    let mut types = Vec::<i8>::with_capacity(indices.len());
+    let (fields, offsets) = if values.offsets().is_none() {
+        // sparse: all fields are taken at the same slots, with `values.offset()` applied
+        let slots = indices
+            .iter()
+            .map(|index| match index {
+                Some(index) => {
+                    let index = index.to_usize();
+                    types.push(values.types()[index]);
+                    Some(values.index(index).1 as u64)
+                }
+                None => {
+                    types.push(null_type);
+                    None
+                }
+            })
+            .collect::<PrimitiveArray<u64>>();
+        let fields = values
+            .fields()
+            .iter()
+            .map(|field| super::take(field.as_ref(), &slots))
+            .collect::<Result<Vec<_>>>()?;
+        (fields, None)
+    } else {
+        // dense: each field is taken at the slots selected from it
+        let mut slots = vec![Vec::<Option<u64>>::new(); number_of_fields];
+        let offsets = indices
+            .iter()
+            .map(|index| {
+                let (field, slot) = match index {
+                    Some(index) => {
+                        let index = index.to_usize();
+                        types.push(values.types()[index]);
+                        let (field, slot) = values.index(index);
+                        (field, Some(slot as u64))
+                    }
+                    None => {
+                        types.push(null_type);
+                        (0, None)
+                    }
+                };
+                slots[field].push(slot);
+                slots[field].len() as i32 - 1
+            })
+            .collect::<Vec<_>>();
+        let fields = values
+            .fields()
+            .iter()
+            .zip(slots)
+            .map(|(field, slots)| super::take(field.as_ref(), &PrimitiveArray::<u64>::from(slots)))
+            .collect::<Result<Vec<_>>>()?;
+        (fields, Some(offsets.into()))
+    };
+
+    UnionArray::try_new(values.data_type().clone(), types.into(), fields, offsets)
+}
@@ -2,6 +2,7 @@
 use crate::error::Error;
 
 pub mod read;
+mod union;
 pub mod write;
 
 #[cfg(feature = "io_parquet_bloom_filter")]
@@ -3,7 +3,7 @@ use std::default::Default;
 
 use parquet2::{
     deserialize::SliceFilteredIter,
-    encoding::{delta_length_byte_array, hybrid_rle, Encoding},
+    encoding::{delta_byte_array, delta_length_byte_array, hybrid_rle, Encoding},
     page::{split_buffer, DataPage, DictPage},
     schema::Repetition,
 };
@@ -87,6 +87,74 @@ impl<'a> Iterator for Delta<'a> {
     }
 }
 
+/// The values of a page encoded with `DELTA_BYTE_ARRAY`. Since each value shares a prefix with
+/// the previous one, the values are materialized into `values` when the page is decoded and
+/// then iterated via `spans` (offset and length of each value).
+#[derive(Debug)]
+pub(super) struct DeltaBytes<I: Iterator<Item = (usize, usize)>> {
+    pub values: Vec<u8>,
+    pub spans: I,
+}
+
+pub(super) type DeltaBytesSpans = std::vec::IntoIter<(usize, usize)>;
+
+impl DeltaBytes<DeltaBytesSpans> {
+    pub fn try_new(page: &DataPage) -> Result<Self> {
+        let (_, _, values) = split_buffer(page)?;
+
+        let mut decoder = delta_byte_array::Decoder::try_new(values)?;
+        let prefixes = decoder
+            .by_ref()
+            .map(|x| x.map(|x| x as usize).map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut decoder = decoder.into_lengths()?;
+        #[allow(clippy::needless_collect)] // we need to consume it to get the suffixes
+        let lengths = decoder
+            .by_ref()
+            .map(|x| x.map(|x| x as usize).map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+        let mut suffixes = decoder.into_values();
+
+        let mut values = Vec::<u8>::with_capacity(suffixes.len());
+        let mut spans = Vec::<(usize, usize)>::with_capacity(prefixes.len());
+        let mut previous = 0;
+        for (prefix, length) in prefixes.into_iter().zip(lengths) {
+            if prefix > spans.last().map_or(0, |(_, length)| *length) || length > suffixes.len() {
+                return Err(Error::oos(
+                    "A DELTA_BYTE_ARRAY page has a prefix or suffix out of bounds",
+                ));
+            }
+            let start = values.len();
+            values.extend_from_within(previous..previous + prefix);
+            let (suffix, remaining) = suffixes.split_at(length);
+            values.extend_from_slice(suffix);
+            suffixes = remaining;
+
+            spans.push((start, prefix + length));
+            previous = start;
+        }
+
+        Ok(Self {
+            values,
+            spans: spans.into_iter(),
+        })
+    }
+}
+
+impl<I: Iterator<Item = (usize, usize)>> DeltaBytes<I> {
+    pub fn len(&self) -> usize {
+        self.spans.size_hint().0
+    }
+
+    pub fn iter(&mut self) -> impl Iterator<Item = &[u8]> + '_ {
+        let values = &self.values;
+        self.spans
+            .by_ref()
+            .map(move |(start, length)| &values[start..start + length])
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct FilteredRequired<'a> {
     pub values: SliceFilteredIter<SizedBinaryIter<'a>>,
@@ -203,6 +271,13 @@ enum State<'a> {
     FilteredOptional(FilteredOptionalPageValidity<'a>, BinaryIter<'a>),
     FilteredRequiredDictionary(FilteredRequiredDictionary<'a>),
     FilteredOptionalDictionary(FilteredOptionalPageValidity<'a>, ValuesDictionary<'a>),
+    DeltaBytes(DeltaBytes<DeltaBytesSpans>),
+    OptionalDeltaBytes(OptionalPageValidity<'a>, DeltaBytes<DeltaBytesSpans>),
+    FilteredDeltaBytes(DeltaBytes<SliceFilteredIter<DeltaBytesSpans>>),
+    FilteredOptionalDeltaBytes(
+        FilteredOptionalPageValidity<'a>,
+        DeltaBytes<DeltaBytesSpans>,
+    ),
 }
 
 impl<'a> utils::PageState<'a> for State<'a> {
@@ -220,6 +295,10 @@ impl<'a> utils::PageState<'a> for State<'a> {
             State::FilteredOptionalDelta(state, _) => state.len(),
             State::FilteredRequiredDictionary(values) => values.len(),
             State::FilteredOptionalDictionary(optional, _) => optional.len(),
+            State::DeltaBytes(state) => state.len(),
+            State::OptionalDeltaBytes(state, _) => state.len(),
+            State::FilteredDeltaBytes(state) => state.len(),
+            State::FilteredOptionalDeltaBytes(state, _) => state.len(),
         }
     }
 }
@@ -301,6 +380,25 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                 FilteredOptionalPageValidity::try_new(page)?,
                 Delta::try_new(page)?,
             )),
+            (Encoding::DeltaByteArray, _, false, false) => {
+                DeltaBytes::try_new(page).map(State::DeltaBytes)
+            }
+            (Encoding::DeltaByteArray, _, true, false) => Ok(State::OptionalDeltaBytes(
+                OptionalPageValidity::try_new(page)?,
+                DeltaBytes::try_new(page)?,
+            )),
+            (Encoding::DeltaByteArray, _, false, true) => {
+                let DeltaBytes { values, spans } = DeltaBytes::try_new(page)?;
+                let rows = get_selected_rows(page);
+                Ok(State::FilteredDeltaBytes(DeltaBytes {
+                    values,
+                    spans: SliceFilteredIter::new(spans, rows),
+                }))
+            }
+            (Encoding::DeltaByteArray, _, true, true) => Ok(State::FilteredOptionalDeltaBytes(
+                FilteredOptionalPageValidity::try_new(page)?,
+                DeltaBytes::try_new(page)?,
+            )),
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -433,6 +531,34 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                         .map(|index| page_dict[index.unwrap() as usize].as_ref()),
                 )
             }
+            State::DeltaBytes(page) => {
+                for x in page.iter().take(additional) {
+                    values.push(x)
+                }
+            }
+            State::OptionalDeltaBytes(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(additional),
+                    values,
+                    page_values.iter(),
+                );
+            }
+            State::FilteredDeltaBytes(page) => {
+                for x in page.iter().take(additional) {
+                    values.push(x)
+                }
+            }
+            State::FilteredOptionalDeltaBytes(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(additional),
+                    values,
+                    page_values.iter(),
+                );
+            }
         }
     }
 
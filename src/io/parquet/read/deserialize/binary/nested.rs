@@ -12,7 +12,7 @@ use crate::{
 };
 
 use super::super::utils::MaybeNext;
-use super::basic::ValuesDictionary;
+use super::basic::{DeltaBytes, DeltaBytesSpans, ValuesDictionary};
 use super::utils::*;
 use super::{super::nested_utils::*, basic::deserialize_plain};
 use super::{
@@ -26,6 +26,8 @@ enum State<'a> {
     Required(BinaryIter<'a>),
     RequiredDictionary(ValuesDictionary<'a>),
     OptionalDictionary(ValuesDictionary<'a>),
+    OptionalDeltaBytes(DeltaBytes<DeltaBytesSpans>),
+    RequiredDeltaBytes(DeltaBytes<DeltaBytesSpans>),
 }
 
 impl<'a> utils::PageState<'a> for State<'a> {
@@ -35,6 +37,8 @@ impl<'a> utils::PageState<'a> for State<'a> {
             State::Required(state) => state.size_hint().0,
             State::RequiredDictionary(required) => required.len(),
             State::OptionalDictionary(optional) => optional.len(),
+            State::OptionalDeltaBytes(optional) => optional.len(),
+            State::RequiredDeltaBytes(required) => required.len(),
         }
     }
 }
@@ -79,6 +83,12 @@ impl<'a, O: Offset> NestedDecoder<'a> for BinaryDecoder<O> {
 
                 Ok(State::Required(values))
             }
+            (Encoding::DeltaByteArray, _, true, false) => {
+                DeltaBytes::try_new(page).map(State::OptionalDeltaBytes)
+            }
+            (Encoding::DeltaByteArray, _, false, false) => {
+                DeltaBytes::try_new(page).map(State::RequiredDeltaBytes)
+            }
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -121,6 +131,15 @@ impl<'a, O: Offset> NestedDecoder<'a> for BinaryDecoder<O> {
                 values.push(item);
                 validity.push(true);
             }
+            State::OptionalDeltaBytes(page) => {
+                let value = page.iter().next().unwrap_or_default();
+                values.push(value);
+                validity.push(true);
+            }
+            State::RequiredDeltaBytes(page) => {
+                let value = page.iter().next().unwrap_or_default();
+                values.push(value);
+            }
         }
         Ok(())
     }
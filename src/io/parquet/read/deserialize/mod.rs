@@ -18,6 +18,7 @@ use crate::{
     array::{Array, DictionaryKey, FixedSizeListArray, ListArray, MapArray},
     datatypes::{DataType, Field, IntervalUnit},
     error::Result,
+    io::parquet::union::{contains_union, from_struct, to_struct_type},
     offset::Offsets,
 };
 
@@ -189,7 +190,7 @@ pub fn n_columns(data_type: &DataType) -> usize {
                 unreachable!()
             }
         }
-        _ => todo!(),
+        Union => n_columns(&to_struct_type(data_type.to_logical_type())),
     }
 }
 
@@ -209,6 +210,15 @@ pub fn column_iter_to_arrays<'a, I: 'a>(
 where
     I: Pages,
 {
+    if contains_union(&field.data_type) {
+        // unions are written as structs of their variants
+        let data_type = field.data_type.clone();
+        let field = Field::new(field.name, to_struct_type(&data_type), field.is_nullable);
+        return Ok(Box::new(
+            column_iter_to_arrays(columns, types, field, chunk_size, num_rows)?
+                .map(move |x| x.and_then(|x| from_struct(x.as_ref(), &data_type))),
+        ));
+    }
     Ok(Box::new(
         columns_to_iter_recursive(columns, types, field, vec![], num_rows, chunk_size)?
             .map(|x| x.map(|x| x.1)),
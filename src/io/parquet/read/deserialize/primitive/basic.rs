@@ -62,6 +62,69 @@ impl<'a> Values<'a> {
     }
 }
 
+/// The largest parquet native type (`INT96`) has 12 bytes
+const MAX_BYTE_STREAMS: usize = 12;
+
+/// An [`Iterator`] over the values of a page encoded with `BYTE_STREAM_SPLIT`, where byte `k`
+/// of value `i` is stored at position `k * num_values + i`. Each item contains the
+/// little-endian bytes of a value in its first `size` bytes.
+#[derive(Debug, Clone)]
+pub(super) struct ByteStreamSplit<'a> {
+    values: &'a [u8],
+    size: usize,
+    num_values: usize,
+    index: usize,
+}
+
+impl<'a> ByteStreamSplit<'a> {
+    pub fn try_new<P: ParquetNativeType>(page: &'a DataPage) -> Result<Self> {
+        let (_, _, values) = split_buffer(page)?;
+        let size = std::mem::size_of::<P>();
+        assert_eq!(values.len() % size, 0);
+        Ok(Self {
+            values,
+            size,
+            num_values: values.len() / size,
+            index: 0,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.num_values - self.index
+    }
+}
+
+impl<'a> Iterator for ByteStreamSplit<'a> {
+    type Item = [u8; MAX_BYTE_STREAMS];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.num_values {
+            return None;
+        }
+        let mut bytes = [0; MAX_BYTE_STREAMS];
+        bytes
+            .iter_mut()
+            .take(self.size)
+            .enumerate()
+            .for_each(|(k, byte)| *byte = self.values[k * self.num_values + self.index]);
+        self.index += 1;
+        Some(bytes)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+/// Decodes an item of [`ByteStreamSplit`] into `P`
+#[inline]
+pub(super) fn decode_split<P: ParquetNativeType>(bytes: [u8; MAX_BYTE_STREAMS]) -> P {
+    decode(&bytes[..std::mem::size_of::<P>()])
+}
+
 #[derive(Debug)]
 pub(super) struct ValuesDictionary<'a, T>
 where
@@ -99,6 +162,10 @@ where
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a, T>),
     FilteredRequired(FilteredRequiredValues<'a>),
     FilteredOptional(FilteredOptionalPageValidity<'a>, Values<'a>),
+    ByteStreamSplitRequired(ByteStreamSplit<'a>),
+    ByteStreamSplitOptional(OptionalPageValidity<'a>, ByteStreamSplit<'a>),
+    FilteredByteStreamSplitRequired(SliceFilteredIter<ByteStreamSplit<'a>>),
+    FilteredByteStreamSplitOptional(FilteredOptionalPageValidity<'a>, ByteStreamSplit<'a>),
 }
 
 impl<'a, T> utils::PageState<'a> for State<'a, T>
@@ -113,6 +180,10 @@ where
             State::OptionalDictionary(optional, _) => optional.len(),
             State::FilteredRequired(values) => values.len(),
             State::FilteredOptional(optional, _) => optional.len(),
+            State::ByteStreamSplitRequired(values) => values.len(),
+            State::ByteStreamSplitOptional(optional, _) => optional.len(),
+            State::FilteredByteStreamSplitRequired(values) => values.size_hint().0,
+            State::FilteredByteStreamSplitOptional(optional, _) => optional.len(),
         }
     }
 }
@@ -190,6 +261,26 @@ where
                 FilteredOptionalPageValidity::try_new(page)?,
                 Values::try_new::<P>(page)?,
             )),
+            (Encoding::ByteStreamSplit, _, false, false) => {
+                ByteStreamSplit::try_new::<P>(page).map(State::ByteStreamSplitRequired)
+            }
+            (Encoding::ByteStreamSplit, _, true, false) => Ok(State::ByteStreamSplitOptional(
+                OptionalPageValidity::try_new(page)?,
+                ByteStreamSplit::try_new::<P>(page)?,
+            )),
+            (Encoding::ByteStreamSplit, _, false, true) => {
+                let values = ByteStreamSplit::try_new::<P>(page)?;
+                let rows = get_selected_rows(page);
+                Ok(State::FilteredByteStreamSplitRequired(
+                    SliceFilteredIter::new(values, rows),
+                ))
+            }
+            (Encoding::ByteStreamSplit, _, true, true) => {
+                Ok(State::FilteredByteStreamSplitOptional(
+                    FilteredOptionalPageValidity::try_new(page)?,
+                    ByteStreamSplit::try_new::<P>(page)?,
+                ))
+            }
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -263,6 +354,42 @@ where
                     page_values.values.by_ref().map(decode).map(self.op),
                 );
             }
+            State::ByteStreamSplitRequired(page_values) => {
+                values.extend(
+                    page_values
+                        .by_ref()
+                        .map(decode_split)
+                        .map(self.op)
+                        .take(remaining),
+                );
+            }
+            State::ByteStreamSplitOptional(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(decode_split).map(self.op),
+                );
+            }
+            State::FilteredByteStreamSplitRequired(page_values) => {
+                values.extend(
+                    page_values
+                        .by_ref()
+                        .map(decode_split)
+                        .map(self.op)
+                        .take(remaining),
+                );
+            }
+            State::FilteredByteStreamSplitOptional(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(decode_split).map(self.op),
+                );
+            }
         }
     }
 
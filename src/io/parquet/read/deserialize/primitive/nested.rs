@@ -15,7 +15,7 @@ use crate::{
 
 use super::super::utils;
 use super::super::Pages;
-use super::basic::{Values, ValuesDictionary};
+use super::basic::{decode_split, ByteStreamSplit, Values, ValuesDictionary};
 use super::{super::nested_utils::*, basic::deserialize_plain};
 
 // The state of a `DataPage` of `Primitive` parquet primitive type
@@ -29,6 +29,8 @@ where
     Required(Values<'a>),
     RequiredDictionary(ValuesDictionary<'a, T>),
     OptionalDictionary(ValuesDictionary<'a, T>),
+    ByteStreamSplitOptional(ByteStreamSplit<'a>),
+    ByteStreamSplitRequired(ByteStreamSplit<'a>),
 }
 
 impl<'a, T> utils::PageState<'a> for State<'a, T>
@@ -41,6 +43,8 @@ where
             State::Required(values) => values.len(),
             State::RequiredDictionary(values) => values.len(),
             State::OptionalDictionary(values) => values.len(),
+            State::ByteStreamSplitOptional(values) => values.len(),
+            State::ByteStreamSplitRequired(values) => values.len(),
         }
     }
 }
@@ -101,6 +105,12 @@ where
             }
             (Encoding::Plain, _, true, false) => Values::try_new::<P>(page).map(State::Optional),
             (Encoding::Plain, _, false, false) => Values::try_new::<P>(page).map(State::Required),
+            (Encoding::ByteStreamSplit, _, true, false) => {
+                ByteStreamSplit::try_new::<P>(page).map(State::ByteStreamSplitOptional)
+            }
+            (Encoding::ByteStreamSplit, _, false, false) => {
+                ByteStreamSplit::try_new::<P>(page).map(State::ByteStreamSplitRequired)
+            }
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -144,6 +154,15 @@ where
                 values.push(value.unwrap_or_default());
                 validity.push(true);
             }
+            State::ByteStreamSplitOptional(page_values) => {
+                let value = page_values.next().map(decode_split).map(self.op);
+                values.push(value.unwrap_or_default());
+                validity.push(true);
+            }
+            State::ByteStreamSplitRequired(page_values) => {
+                let value = page_values.next().map(decode_split).map(self.op);
+                values.push(value.unwrap_or_default());
+            }
         }
         Ok(())
     }
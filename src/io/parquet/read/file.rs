@@ -161,22 +161,7 @@ impl<R: Read + Seek> RowGroupReader<R> {
 
         let pages = self.page_indexes.as_mut().and_then(|iter| iter.next());
 
-        // the number of rows depends on whether indexes are selected or not.
-        let num_rows = pages
-            .as_ref()
-            .map(|x| {
-                // first field, first column within that field
-                x[0][0]
-                    .iter()
-                    .map(|page| {
-                        page.selected_rows
-                            .iter()
-                            .map(|interval| interval.length)
-                            .sum::<usize>()
-                    })
-                    .sum()
-            })
-            .unwrap_or_else(|| row_group.num_rows());
+        let num_rows = selected_rows(&row_group, pages.as_ref());
 
         let column_chunks = read_columns_many(
             &mut self.reader,
@@ -204,3 +189,25 @@ impl<R: Read + Seek> Iterator for RowGroupReader<R> {
         self.row_groups.size_hint()
     }
 }
+
+/// Returns the number of rows of `row_group` selected by `pages`, or all its rows when
+/// indexes are not used.
+pub(super) fn selected_rows(
+    row_group: &RowGroupMetaData,
+    pages: Option<&Vec<Vec<Vec<FilteredPage>>>>,
+) -> usize {
+    pages
+        .map(|x| {
+            // first field, first column within that field
+            x[0][0]
+                .iter()
+                .map(|page| {
+                    page.selected_rows
+                        .iter()
+                        .map(|interval| interval.length)
+                        .sum::<usize>()
+                })
+                .sum()
+        })
+        .unwrap_or_else(|| row_group.num_rows())
+}
@@ -0,0 +1,121 @@
+//! Async reader of the row groups of a parquet file
+use futures::{future::BoxFuture, stream::BoxStream, AsyncRead, AsyncSeek, Stream, StreamExt};
+use parquet2::indexes::FilteredPage;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::Result;
+
+use super::file::selected_rows;
+use super::{read_columns_many_async, RowGroupDeserializer, RowGroupMetaData};
+
+/// A [`Stream`] of [`Chunk`]s coming from row groups of a parquet file.
+///
+/// This is the async counterpart of [`FileReader`](super::FileReader): each row group is
+/// sequentially read to memory via [`read_columns_many_async`] and deserialized in chunks
+/// until either the limit or the last row group ends.
+/// # Implementation
+/// Reading a row group is IO-bounded and its columns are read concurrently from readers
+/// created by `reader_factory`. Deserializing is CPU-bounded and happens when the stream
+/// is polled.
+pub struct FileStream<'a> {
+    stream: BoxStream<'a, Result<Chunk<Box<dyn Array>>>>,
+    schema: Schema,
+}
+
+impl<'a> FileStream<'a> {
+    /// Returns a new [`FileStream`].
+    ///
+    /// Only the fields of `schema` are read, so projections are declared by e.g.
+    /// [`Schema::filter`] on the file's schema. `page_indexes`, when provided, has one entry
+    /// per row group in `row_groups`.
+    /// # Panics
+    /// Iff `page_indexes` and `row_groups` have different lengths.
+    pub fn new<R, F>(
+        reader_factory: F,
+        row_groups: Vec<RowGroupMetaData>,
+        schema: Schema,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        page_indexes: Option<Vec<Vec<Vec<Vec<FilteredPage>>>>>,
+    ) -> Self
+    where
+        R: AsyncRead + AsyncSeek + Send + Unpin + 'a,
+        F: Fn() -> BoxFuture<'a, std::io::Result<R>> + Clone + Send + Sync + 'a,
+    {
+        if let Some(pages) = &page_indexes {
+            assert_eq!(pages.len(), row_groups.len())
+        }
+        let stream = Self::stream(
+            reader_factory,
+            row_groups,
+            schema.clone(),
+            chunk_size,
+            limit,
+            page_indexes,
+        );
+        Self { stream, schema }
+    }
+
+    /// Returns the [`Schema`] associated to this file.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn stream<R, F>(
+        reader_factory: F,
+        row_groups: Vec<RowGroupMetaData>,
+        schema: Schema,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        page_indexes: Option<Vec<Vec<Vec<Vec<FilteredPage>>>>>,
+    ) -> BoxStream<'a, Result<Chunk<Box<dyn Array>>>>
+    where
+        R: AsyncRead + AsyncSeek + Send + Unpin + 'a,
+        F: Fn() -> BoxFuture<'a, std::io::Result<R>> + Clone + Send + Sync + 'a,
+    {
+        async_stream::try_stream! {
+            let mut remaining_rows = limit.unwrap_or(usize::MAX);
+            let mut page_indexes = page_indexes.map(|pages| pages.into_iter());
+            for row_group in row_groups {
+                if schema.fields.is_empty() || remaining_rows == 0 {
+                    break;
+                }
+                let pages = page_indexes.as_mut().and_then(|iter| iter.next());
+
+                // the number of rows depends on whether indexes are selected or not.
+                let num_rows = selected_rows(&row_group, pages.as_ref());
+
+                let column_chunks = read_columns_many_async(
+                    reader_factory.clone(),
+                    &row_group,
+                    schema.fields.clone(),
+                    chunk_size,
+                    Some(remaining_rows),
+                    pages,
+                )
+                .await?;
+
+                let row_group =
+                    RowGroupDeserializer::new(column_chunks, num_rows, Some(remaining_rows));
+                remaining_rows = remaining_rows.saturating_sub(num_rows);
+                for chunk in row_group {
+                    yield chunk?;
+                }
+            }
+        }
+        .boxed()
+    }
+}
+
+impl<'a> Stream for FileStream<'a> {
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.get_mut().stream.poll_next_unpin(cx)
+    }
+}
@@ -0,0 +1,222 @@
+use std::io::{Read, Seek};
+
+use parquet2::indexes::{FilteredPage, Interval};
+
+use crate::array::{growable::make_growable, new_empty_array, Array, BooleanArray};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType, Schema};
+use crate::error::{Error, Result};
+
+use super::indexes::{has_page_locations, read_selected_pages, selection_to_intervals};
+use super::{read_columns_many, RowGroupMetaData};
+
+// whether the field can be deserialized from pages filtered by rows
+fn is_flat(data_type: &DataType) -> bool {
+    !matches!(
+        data_type.to_physical_type(),
+        PhysicalType::List
+            | PhysicalType::LargeList
+            | PhysicalType::FixedSizeList
+            | PhysicalType::Struct
+            | PhysicalType::Map
+            | PhysicalType::Union
+    )
+}
+
+/// Deserializes each of `fields` of `row_group` into a single array.
+fn read_arrays<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    pages: Option<Vec<Vec<Vec<FilteredPage>>>>,
+) -> Result<Vec<Box<dyn Array>>> {
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type.clone())
+        .collect::<Vec<_>>();
+    read_columns_many(reader, row_group, fields, None, None, pages)?
+        .into_iter()
+        .zip(data_types)
+        .map(|(mut iter, data_type)| {
+            // without a chunk size, the row group is deserialized into at most one array
+            iter.next()
+                .unwrap_or_else(|| Ok(new_empty_array(data_type)))
+        })
+        .collect()
+}
+
+/// Returns the rows of `array` in `intervals`.
+fn take_intervals(array: &dyn Array, intervals: &[Interval], length: usize) -> Box<dyn Array> {
+    if length == array.len() {
+        return array.to_boxed();
+    }
+    let mut growable = make_growable(&[array], false, length);
+    intervals
+        .iter()
+        .for_each(|interval| growable.extend(0, interval.start, interval.length));
+    growable.as_box()
+}
+
+/// Reads the rows of `row_group` selected by `predicate` (late materialization).
+///
+/// The fields of `fields` indexed by `predicate_fields` are deserialized first and passed to
+/// `predicate`, which returns the rows to select (those whose values are valid and `true`).
+/// The remaining fields are then deserialized only for the selected rows: when the row group
+/// has page locations, pages without selected rows are not read nor decompressed.
+///
+/// Returns a [`Chunk`] with one array per field of `fields`, empty if no row is selected.
+/// # Implementation
+/// Nested fields and row groups without page locations are deserialized in full and filtered
+/// afterwards.
+/// # Errors
+/// Errors iff a predicate field is out of bounds, `predicate` errors or returns a selection
+/// whose length differs from the number of rows, or reading fails.
+pub fn read_row_group_filtered<R, F>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: &[Field],
+    predicate_fields: &[usize],
+    predicate: F,
+) -> Result<Chunk<Box<dyn Array>>>
+where
+    R: Read + Seek,
+    F: FnOnce(&[Box<dyn Array>]) -> Result<BooleanArray>,
+{
+    if let Some(index) = predicate_fields.iter().find(|x| **x >= fields.len()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The predicate field {index} is out of bounds of the {} fields",
+            fields.len()
+        )));
+    }
+    let num_rows = row_group.num_rows();
+
+    let predicate_arrays = read_arrays(
+        reader,
+        row_group,
+        predicate_fields
+            .iter()
+            .map(|x| fields[*x].clone())
+            .collect(),
+        None,
+    )?;
+    let selection = predicate(&predicate_arrays)?;
+    if selection.len() != num_rows {
+        return Err(Error::InvalidArgumentError(format!(
+            "The predicate must select from the {num_rows} rows of the row group, but selected from {}",
+            selection.len()
+        )));
+    }
+    let intervals = selection_to_intervals(&selection);
+    let length = intervals.iter().map(|x| x.length).sum::<usize>();
+
+    let mut arrays = vec![None; fields.len()];
+    for (index, array) in predicate_fields.iter().zip(predicate_arrays) {
+        arrays[*index] = Some(take_intervals(array.as_ref(), &intervals, length));
+    }
+
+    // the remaining fields are split between those deserialized from the selected pages
+    // and those deserialized in full
+    let (paged, full): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| arrays[*index].is_none())
+        .partition(|(_, field)| is_flat(&field.data_type) && has_page_locations(row_group));
+
+    if length == 0 {
+        paged.into_iter().chain(full).for_each(|(index, field)| {
+            arrays[index] = Some(new_empty_array(field.data_type.clone()))
+        });
+    } else {
+        let (indices, paged): (Vec<_>, Vec<_>) =
+            paged.into_iter().map(|(i, f)| (i, f.clone())).unzip();
+        let paged = if paged.is_empty() {
+            vec![]
+        } else {
+            let pages = read_selected_pages(reader, row_group, &paged, &intervals)?;
+            read_arrays(reader, row_group, paged, Some(pages))?
+        };
+        indices
+            .into_iter()
+            .zip(paged)
+            .for_each(|(index, array)| arrays[index] = Some(array));
+
+        let (indices, full): (Vec<_>, Vec<_>) =
+            full.into_iter().map(|(i, f)| (i, f.clone())).unzip();
+        let full = read_arrays(reader, row_group, full, None)?;
+        indices.into_iter().zip(full).for_each(|(index, array)| {
+            arrays[index] = Some(take_intervals(array.as_ref(), &intervals, length))
+        });
+    }
+
+    Chunk::try_new(arrays.into_iter().map(|x| x.unwrap()).collect())
+}
+
+/// An iterator of [`Chunk`]s of the rows of the row groups of a parquet file selected by a
+/// predicate, one per row group with selected rows. See [`read_row_group_filtered`].
+/// # Implementation
+/// This iterator is single threaded on both IO-bounded and CPU-bounded tasks, and mixes them.
+pub struct FilteredFileReader<R, F>
+where
+    R: Read + Seek,
+    F: FnMut(&[Box<dyn Array>]) -> Result<BooleanArray>,
+{
+    reader: R,
+    row_groups: std::vec::IntoIter<RowGroupMetaData>,
+    schema: Schema,
+    predicate_fields: Vec<usize>,
+    predicate: F,
+}
+
+impl<R, F> FilteredFileReader<R, F>
+where
+    R: Read + Seek,
+    F: FnMut(&[Box<dyn Array>]) -> Result<BooleanArray>,
+{
+    /// Returns a new [`FilteredFileReader`] of the `schema`'s fields of `row_groups`, where
+    /// `predicate` is evaluated on the fields indexed by `predicate_fields`.
+    pub fn new(
+        reader: R,
+        row_groups: Vec<RowGroupMetaData>,
+        schema: Schema,
+        predicate_fields: Vec<usize>,
+        predicate: F,
+    ) -> Self {
+        Self {
+            reader,
+            row_groups: row_groups.into_iter(),
+            schema,
+            predicate_fields,
+            predicate,
+        }
+    }
+
+    /// Returns the [`Schema`] associated to this file.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+impl<R, F> Iterator for FilteredFileReader<R, F>
+where
+    R: Read + Seek,
+    F: FnMut(&[Box<dyn Array>]) -> Result<BooleanArray>,
+{
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for row_group in self.row_groups.by_ref() {
+            let chunk = read_row_group_filtered(
+                &mut self.reader,
+                &row_group,
+                &self.schema.fields,
+                &self.predicate_fields,
+                &mut self.predicate,
+            );
+            match chunk {
+                Ok(chunk) if chunk.is_empty() => continue,
+                other => return Some(other),
+            }
+        }
+        None
+    }
+}
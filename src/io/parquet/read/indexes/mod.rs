@@ -16,7 +16,8 @@ mod primitive;
 use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
-use crate::array::UInt64Array;
+use crate::array::{BooleanArray, UInt64Array};
+use crate::bitmap::utils::SlicesIterator;
 use crate::datatypes::{Field, PrimitiveType};
 use crate::{
     array::Array,
@@ -374,13 +375,65 @@ pub fn read_filtered_pages<
 
     let intervals = predicate(&indexes, &intervals);
 
+    select_fields_pages(&intervals, locations, num_rows)
+}
+
+fn select_fields_pages(
+    intervals: &[Interval],
+    locations: Vec<Vec<&Vec<PageLocation>>>,
+    num_rows: usize,
+) -> Result<Vec<Vec<Vec<FilteredPage>>>, Error> {
     locations
         .into_iter()
         .map(|locations| {
             locations
                 .into_iter()
-                .map(|locations| Ok(select_pages(&intervals, locations, num_rows)?))
+                .map(|locations| Ok(select_pages(intervals, locations, num_rows)?))
                 .collect::<Result<Vec<_>, Error>>()
         })
         .collect()
 }
+
+/// Checks whether the row group has page locations (offset indexes), required to select
+/// pages via [`read_selected_pages`].
+pub fn has_page_locations(row_group: &RowGroupMetaData) -> bool {
+    row_group
+        .columns()
+        .iter()
+        .all(|chunk| chunk.column_chunk().offset_index_offset.is_some())
+}
+
+/// Returns the sorted and disjoint [`Interval`]s of the rows whose values in `selection` are
+/// valid and `true`.
+pub fn selection_to_intervals(selection: &BooleanArray) -> Vec<Interval> {
+    let selected = match selection.validity() {
+        Some(validity) => selection.values() & validity,
+        None => selection.values().clone(),
+    };
+    SlicesIterator::new(&selected)
+        .map(|(start, length)| Interval::new(start, length))
+        .collect()
+}
+
+/// Reads the page locations (IO-bounded) of the `fields` of `row_group` and returns the
+/// [`FilteredPage`]s that select the rows in `intervals`, sorted and disjoint ranges of
+/// rows of the row group.
+///
+/// Like [`read_filtered_pages`], this returns one item per `field`, with one item per column.
+/// Pages without selected rows are not read.
+/// # Error
+/// Errors iff the page locations can't be read or are inconsistent with the row group.
+pub fn read_selected_pages<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: &[Field],
+    intervals: &[Interval],
+) -> Result<Vec<Vec<Vec<FilteredPage>>>, Error> {
+    let locations = read_pages_locations(reader, row_group.columns())?;
+    let locations = fields
+        .iter()
+        .map(|field| get_field_pages(row_group.columns(), &locations, &field.name))
+        .collect::<Vec<_>>();
+
+    select_fields_pages(intervals, locations, row_group.num_rows())
+}
@@ -3,7 +3,11 @@
 
 mod deserialize;
 mod file;
+#[cfg(feature = "io_parquet_async")]
+mod file_async;
+mod filter;
 pub mod indexes;
+mod predicate;
 mod row_group;
 pub mod schema;
 pub mod statistics;
@@ -43,6 +47,14 @@ pub use deserialize::{
     nested_column_iter_to_arrays, InitNested, NestedArrayIter, NestedState, StructIterator,
 };
 pub use file::{FileReader, RowGroupReader};
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
+pub use file_async::FileStream;
+pub use filter::{read_row_group_filtered, FilteredFileReader};
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+pub use predicate::prune_row_groups_with_bloom_filters;
+pub use predicate::{prune_row_groups, RowGroupPredicate};
 pub use row_group::*;
 pub use schema::{infer_schema, FileMetaData};
 
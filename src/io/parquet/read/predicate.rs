@@ -0,0 +1,457 @@
+//! Pruning of row groups by predicates evaluated against their statistics and bloom filters.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+#[cfg(feature = "io_parquet_bloom_filter")]
+use std::io::{Read, Seek};
+
+use parquet2::metadata::RowGroupMetaData;
+
+use crate::array::{ord::build_compare, Array, DictionaryArray, StructArray, UInt64Array};
+use crate::datatypes::{DataType, Field};
+use crate::error::{Error, Result};
+
+use super::statistics::{deserialize, Statistics};
+
+/// A predicate over the rows of a row group, used to prune row groups whose statistics
+/// (and bloom filters) prove that no row satisfies it.
+///
+/// A column is the name of a field, or the path of names separated by `.` to a field nested in
+/// structs (e.g. `"a.b"` for the field `b` of the struct field `a`). Values are arrays whose
+/// [`DataType`] equals the column's (or the values' of a dictionary column). Comparisons
+/// with nulls are never satisfied, i.e. `Not(Eq(a, 1))` does not match rows where `a` is
+/// null.
+#[derive(Debug, Clone)]
+pub enum RowGroupPredicate {
+    /// The column equals the single value of the array
+    Eq(String, Box<dyn Array>),
+    /// The column is within the bounds, where `None` is unbounded. Bounds are inclusive and
+    /// have a single value.
+    Range {
+        /// The name of the column
+        column: String,
+        /// The lower bound
+        min: Option<Box<dyn Array>>,
+        /// The upper bound
+        max: Option<Box<dyn Array>>,
+    },
+    /// The column equals one of the valid values of the array
+    In(String, Box<dyn Array>),
+    /// The column is null
+    IsNull(String),
+    /// Both predicates are satisfied
+    And(Box<RowGroupPredicate>, Box<RowGroupPredicate>),
+    /// At least one of the predicates is satisfied
+    Or(Box<RowGroupPredicate>, Box<RowGroupPredicate>),
+    /// The predicate is not satisfied
+    Not(Box<RowGroupPredicate>),
+}
+
+impl RowGroupPredicate {
+    /// Returns a predicate satisfied when both `self` and `other` are.
+    pub fn and(self, other: Self) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    /// Returns a predicate satisfied when `self` or `other` is.
+    pub fn or(self, other: Self) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Returns a predicate satisfied when `self` is not.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    fn columns<'a>(&'a self, columns: &mut Vec<&'a str>) {
+        match self {
+            Self::Eq(column, _)
+            | Self::Range { column, .. }
+            | Self::In(column, _)
+            | Self::IsNull(column) => columns.push(column),
+            Self::And(lhs, rhs) | Self::Or(lhs, rhs) => {
+                lhs.columns(columns);
+                rhs.columns(columns);
+            }
+            Self::Not(predicate) => predicate.columns(columns),
+        }
+    }
+
+    fn check(&self, fields: &[Field]) -> Result<()> {
+        let (column, values): (_, Vec<(&dyn Array, bool)>) = match self {
+            Self::Eq(column, value) => (column, vec![(value.as_ref(), true)]),
+            Self::Range { column, min, max } => (
+                column,
+                min.iter()
+                    .chain(max.iter())
+                    .map(|x| (x.as_ref(), true))
+                    .collect(),
+            ),
+            Self::In(column, values) => (column, vec![(values.as_ref(), false)]),
+            Self::IsNull(column) => (column, vec![]),
+            Self::And(lhs, rhs) | Self::Or(lhs, rhs) => {
+                return lhs.check(fields).and_then(|_| rhs.check(fields))
+            }
+            Self::Not(predicate) => return predicate.check(fields),
+        };
+        let field = find_column(fields, column)?.field();
+        let data_type = match field.data_type.to_logical_type() {
+            DataType::Dictionary(_, values, _) => values.as_ref(),
+            data_type => data_type,
+        };
+        values.into_iter().try_for_each(|(values, single)| {
+            if values.data_type() != data_type {
+                Err(Error::InvalidArgumentError(format!(
+                    "The column \"{column}\" of type {data_type:?} cannot be compared with values of type {:?}",
+                    values.data_type()
+                )))
+            } else if single && values.len() != 1 {
+                Err(Error::InvalidArgumentError(format!(
+                    "The values compared with the column \"{column}\" must have a single value"
+                )))
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+/// The fields from a field of the schema to a column of a predicate, with their indices in
+/// their parent's fields
+struct Column<'a>(Vec<(usize, &'a Field)>);
+
+impl<'a> Column<'a> {
+    fn field(&self) -> &'a Field {
+        self.0.last().unwrap().1
+    }
+
+    /// The names of the path of the column in the parquet schema
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    fn names(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.iter().map(|(_, field)| field.name.as_str())
+    }
+}
+
+// pushes the path of the field named `name` or nested in a struct as `{struct}.{name}`
+fn find_path<'a>(fields: &'a [Field], name: &str, path: &mut Vec<(usize, &'a Field)>) -> bool {
+    if let Some(found) = fields.iter().enumerate().find(|(_, x)| x.name == name) {
+        path.push(found);
+        return true;
+    }
+    fields.iter().enumerate().any(|(index, field)| {
+        let (DataType::Struct(children), Some(rest)) = (
+            field.data_type.to_logical_type(),
+            name.strip_prefix(field.name.as_str())
+                .and_then(|x| x.strip_prefix('.')),
+        ) else {
+            return false;
+        };
+        path.push((index, field));
+        let found = find_path(children, rest, path);
+        if !found {
+            path.pop();
+        }
+        found
+    })
+}
+
+fn find_column<'a>(fields: &'a [Field], name: &str) -> Result<Column<'a>> {
+    let mut path = vec![];
+    if find_path(fields, name, &mut path) {
+        Ok(Column(path))
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "The predicate's column \"{name}\" does not exist"
+        )))
+    }
+}
+
+/// Returns the statistics of `column`, nested in the statistics of its field of the schema.
+fn column_statistics(column: &Column, row_groups: &[RowGroupMetaData]) -> Result<Statistics> {
+    let statistics = deserialize(column.0[0].1, row_groups)?;
+    Ok(column.0[1..]
+        .iter()
+        .fold(statistics, |statistics, (index, _)| {
+            let child = |array: Box<dyn Array>| {
+                array
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .unwrap()
+                    .values()[*index]
+                    .clone()
+            };
+            Statistics {
+                null_count: child(statistics.null_count),
+                distinct_count: child(statistics.distinct_count),
+                min_value: child(statistics.min_value),
+                max_value: child(statistics.max_value),
+            }
+        }))
+}
+
+/// Whether some rows of a row group may satisfy a predicate and whether some may not satisfy it.
+/// Rows where the predicate is null do neither.
+#[derive(Debug, Clone, Copy)]
+struct Truth {
+    any_true: bool,
+    any_false: bool,
+}
+
+const UNKNOWN: Truth = Truth {
+    any_true: true,
+    any_false: true,
+};
+
+const ALL_NULL: Truth = Truth {
+    any_true: false,
+    any_false: false,
+};
+
+// the statistics of dictionary-encoded columns are dictionaries whose keys are `0..len`
+fn dictionary_values(array: &dyn Array) -> &dyn Array {
+    if let DataType::Dictionary(key_type, _, _) = array.data_type().to_logical_type() {
+        match_integer_type!(key_type, |$T| {
+            array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap()
+                .values()
+                .as_ref()
+        })
+    } else {
+        array
+    }
+}
+
+/// The context to evaluate a predicate for a row group
+struct Context<'a, F: FnMut(&str, &dyn Array) -> Result<bool>> {
+    statistics: &'a HashMap<&'a str, Statistics>,
+    index: usize,
+    num_rows: u64,
+    /// Returns whether the column may contain one of the valid values of the array
+    may_contain: F,
+}
+
+impl<'a, F: FnMut(&str, &dyn Array) -> Result<bool>> Context<'a, F> {
+    /// Returns the min and max of `column`, or `None` if they are unknown or if all its values
+    /// are null.
+    fn min_max(&self, column: &str) -> Option<(&'a dyn Array, &'a dyn Array)> {
+        let statistics = self.statistics.get(column)?;
+        // the statistics of nested columns are nested and are not used
+        statistics
+            .null_count
+            .as_any()
+            .downcast_ref::<UInt64Array>()?;
+        let min = dictionary_values(statistics.min_value.as_ref());
+        let max = dictionary_values(statistics.max_value.as_ref());
+        (min.is_valid(self.index) && max.is_valid(self.index)).then_some((min, max))
+    }
+
+    fn null_count(&self, column: &str) -> Option<u64> {
+        let statistics = self.statistics.get(column)?;
+        statistics
+            .null_count
+            .as_any()
+            .downcast_ref::<UInt64Array>()?
+            .get(self.index)
+    }
+
+    // the truth when the min and max are unknown
+    fn unknown(&self, column: &str) -> Truth {
+        if self.null_count(column) == Some(self.num_rows) {
+            ALL_NULL
+        } else {
+            UNKNOWN
+        }
+    }
+
+    fn evaluate_in(&mut self, column: &str, values: &dyn Array) -> Result<Truth> {
+        let index = self.index;
+        let Some((min, max)) = self.min_max(column) else {
+            return Ok(self.unknown(column));
+        };
+        let min_cmp = build_compare(values, min)?;
+        let max_cmp = build_compare(values, max)?;
+        let mut valid = (0..values.len()).filter(|i| values.is_valid(*i));
+
+        let any_true = valid
+            .clone()
+            .any(|i| min_cmp(i, index) != Ordering::Less && max_cmp(i, index) != Ordering::Greater);
+        // all values are equal to one of `values`
+        let all_true = build_compare(min, max)?(index, index) == Ordering::Equal
+            && valid.any(|i| min_cmp(i, index) == Ordering::Equal);
+
+        let any_true = any_true && (self.may_contain)(column, values)?;
+        Ok(Truth {
+            any_true,
+            any_false: !all_true,
+        })
+    }
+
+    fn evaluate_range(
+        &mut self,
+        column: &str,
+        lower: Option<&dyn Array>,
+        upper: Option<&dyn Array>,
+    ) -> Result<Truth> {
+        let index = self.index;
+        let Some((min, max)) = self.min_max(column) else {
+            return Ok(self.unknown(column));
+        };
+        // whether `lhs` is unbounded or its order against the value of `rhs` is not `excluded`
+        let cmp = |lhs: Option<&dyn Array>, rhs: &dyn Array, excluded| -> Result<bool> {
+            Ok(match lhs {
+                Some(lhs) => build_compare(lhs, rhs)?(0, index) != excluded,
+                None => true,
+            })
+        };
+        let le = |lhs, rhs| cmp(lhs, rhs, Ordering::Greater);
+        let ge = |lhs, rhs| cmp(lhs, rhs, Ordering::Less);
+        Ok(Truth {
+            any_true: le(lower, max)? && ge(upper, min)?,
+            any_false: !(le(lower, min)? && ge(upper, max)?),
+        })
+    }
+
+    fn evaluate(&mut self, predicate: &RowGroupPredicate) -> Result<Truth> {
+        use RowGroupPredicate::*;
+        Ok(match predicate {
+            Eq(column, value) | In(column, value) => self.evaluate_in(column, value.as_ref())?,
+            Range { column, min, max } => {
+                self.evaluate_range(column, min.as_deref(), max.as_deref())?
+            }
+            IsNull(column) => match self.null_count(column) {
+                Some(null_count) => Truth {
+                    any_true: null_count > 0,
+                    any_false: null_count < self.num_rows,
+                },
+                None => UNKNOWN,
+            },
+            And(lhs, rhs) => {
+                let lhs = self.evaluate(lhs)?;
+                let rhs = self.evaluate(rhs)?;
+                Truth {
+                    any_true: lhs.any_true && rhs.any_true,
+                    any_false: lhs.any_false || rhs.any_false,
+                }
+            }
+            Or(lhs, rhs) => {
+                let lhs = self.evaluate(lhs)?;
+                let rhs = self.evaluate(rhs)?;
+                Truth {
+                    any_true: lhs.any_true || rhs.any_true,
+                    any_false: lhs.any_false && rhs.any_false,
+                }
+            }
+            Not(predicate) => {
+                let truth = self.evaluate(predicate)?;
+                Truth {
+                    any_true: truth.any_false,
+                    any_false: truth.any_true,
+                }
+            }
+        })
+    }
+}
+
+fn prune<F>(
+    row_groups: Vec<RowGroupMetaData>,
+    fields: &[Field],
+    predicate: &RowGroupPredicate,
+    mut may_contain: F,
+) -> Result<Vec<RowGroupMetaData>>
+where
+    F: FnMut(&RowGroupMetaData, &Column, &dyn Array) -> Result<bool>,
+{
+    predicate.check(fields)?;
+
+    let mut columns = vec![];
+    predicate.columns(&mut columns);
+    let statistics = columns
+        .into_iter()
+        .map(|name| {
+            let column = find_column(fields, name)?;
+            Ok((name, column_statistics(&column, &row_groups)?))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    let mut selected = Vec::with_capacity(row_groups.len());
+    for (index, group) in row_groups.iter().enumerate() {
+        let mut context = Context {
+            statistics: &statistics,
+            index,
+            num_rows: group.num_rows() as u64,
+            may_contain: |column: &str, values: &dyn Array| {
+                may_contain(group, &find_column(fields, column)?, values)
+            },
+        };
+        if context.evaluate(predicate)?.any_true {
+            selected.push(index);
+        }
+    }
+
+    Ok(row_groups
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| selected.binary_search(index).is_ok())
+        .map(|(_, group)| group)
+        .collect())
+}
+
+/// Returns the row groups of `row_groups` that may contain rows satisfying `predicate`,
+/// according to the statistics of their columns.
+///
+/// `fields` are the fields of the file (e.g. from [`infer_schema`](super::infer_schema)) and the
+/// returned row groups can be passed to [`FileReader::new`](super::FileReader::new). Row groups
+/// without statistics for a column are assumed to contain any value of it.
+/// # Errors
+/// Errors iff a column of `predicate` is not in `fields`, its values have a different
+/// [`DataType`], or the statistics cannot be deserialized.
+pub fn prune_row_groups(
+    row_groups: Vec<RowGroupMetaData>,
+    fields: &[Field],
+    predicate: &RowGroupPredicate,
+) -> Result<Vec<RowGroupMetaData>> {
+    prune(row_groups, fields, predicate, |_, _, _| Ok(true))
+}
+
+/// Like [`prune_row_groups`], but also reads the bloom filters of the columns compared for
+/// equality from `reader`, pruning the row groups that certainly do not contain the values.
+/// # Errors
+/// Like [`prune_row_groups`], and iff a bloom filter cannot be read.
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+pub fn prune_row_groups_with_bloom_filters<R: Read + Seek>(
+    reader: &mut R,
+    row_groups: Vec<RowGroupMetaData>,
+    fields: &[Field],
+    predicate: &RowGroupPredicate,
+) -> Result<Vec<RowGroupMetaData>> {
+    use parquet2::bloom_filter::{is_in_set, read};
+
+    use crate::io::parquet::write::hash_array;
+
+    let mut bitset = vec![];
+    prune(row_groups, fields, predicate, |group, column, values| {
+        // fields nested in lists or maps have no column chunk of their path
+        let Some(column) = group.columns().iter().find(|x| {
+            x.descriptor()
+                .path_in_schema
+                .iter()
+                .map(|x| x.as_str())
+                .eq(column.names())
+        }) else {
+            return Ok(true);
+        };
+        read(column, &mut *reader, &mut bitset)?;
+        if bitset.is_empty() {
+            return Ok(true);
+        }
+        let mut contains = false;
+        match hash_array(values, &mut |hash| contains |= is_in_set(&bitset, hash)) {
+            Ok(()) => Ok(contains),
+            // the filter was written by hashing values of a type not known here
+            Err(_) => Ok(true),
+        }
+    })
+}
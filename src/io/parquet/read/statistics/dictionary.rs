@@ -12,7 +12,7 @@ pub struct DynMutableDictionary {
 
 impl DynMutableDictionary {
     pub fn try_with_capacity(data_type: DataType, capacity: usize) -> Result<Self> {
-        let inner = if let DataType::Dictionary(_, inner, _) = &data_type {
+        let inner = if let DataType::Dictionary(_, inner, _) = data_type.to_logical_type() {
             inner.as_ref()
         } else {
             unreachable!()
@@ -36,15 +36,17 @@ impl MutableArray for DynMutableDictionary {
         self.inner.validity()
     }
 
+    // the keys are `0..len`, where those beyond the range of the key type are null
     fn as_box(&mut self) -> Box<dyn Array> {
         let inner = self.inner.as_box();
         match self.data_type.to_physical_type() {
             PhysicalType::Dictionary(key) => match_integer_type!(key, |$T| {
-                let keys: Vec<$T> = (0..inner.len() as $T).collect();
-                let keys = PrimitiveArray::<$T>::from_vec(keys);
+                let keys = (0..inner.len())
+                    .map(|x| $T::try_from(x).ok())
+                    .collect::<PrimitiveArray<$T>>();
                 Box::new(DictionaryArray::<$T>::try_new(self.data_type.clone(), keys, inner).unwrap())
             }),
-            _ => todo!(),
+            _ => unreachable!(),
         }
     }
 
@@ -57,14 +59,14 @@ impl MutableArray for DynMutableDictionary {
     }
 
     fn push_null(&mut self) {
-        todo!()
+        self.inner.push_null()
     }
 
-    fn reserve(&mut self, _: usize) {
-        todo!();
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
     }
 
     fn shrink_to_fit(&mut self) {
-        todo!()
+        self.inner.shrink_to_fit()
     }
 }
@@ -13,11 +13,18 @@ pub struct DynMutableListArray {
 
 impl DynMutableListArray {
     pub fn try_with_capacity(data_type: DataType, capacity: usize) -> Result<Self> {
-        let inner = match data_type.to_logical_type() {
-            DataType::List(inner) | DataType::LargeList(inner) => inner.data_type(),
+        let (data_type, inner) = match data_type.to_logical_type() {
+            DataType::List(inner) | DataType::LargeList(inner) => {
+                let inner = make_mutable(inner.data_type(), capacity)?;
+                (data_type, inner)
+            }
+            // a row group has a single statistic, not one per item of the fixed size
+            DataType::FixedSizeList(inner, _) => (
+                DataType::List(inner.clone()),
+                make_mutable(inner.data_type(), capacity)?,
+            ),
             _ => unreachable!(),
         };
-        let inner = make_mutable(inner, capacity)?;
 
         Ok(Self { data_type, inner })
     }
@@ -73,14 +80,14 @@ impl MutableArray for DynMutableListArray {
     }
 
     fn push_null(&mut self) {
-        todo!()
+        self.inner.push_null()
     }
 
-    fn reserve(&mut self, _: usize) {
-        todo!();
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
     }
 
     fn shrink_to_fit(&mut self) {
-        todo!()
+        self.inner.shrink_to_fit()
     }
 }
@@ -2,6 +2,7 @@ use crate::{
     array::{Array, MapArray, MutableArray},
     datatypes::DataType,
     error::Error,
+    offset::Offsets,
 };
 
 use super::make_mutable;
@@ -38,10 +39,13 @@ impl MutableArray for DynMutableMapArray {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
+        let inner = self.inner.as_box();
+        // one entry per row group
+        let offsets = Offsets::try_from_lengths(std::iter::repeat(1).take(inner.len())).unwrap();
         Box::new(MapArray::new(
             self.data_type.clone(),
-            vec![0, self.inner.len() as i32].try_into().unwrap(),
-            self.inner.as_box(),
+            offsets.into(),
+            inner,
             None,
         ))
     }
@@ -55,14 +59,14 @@ impl MutableArray for DynMutableMapArray {
     }
 
     fn push_null(&mut self) {
-        todo!()
+        self.inner.push_null()
     }
 
-    fn reserve(&mut self, _: usize) {
-        todo!();
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
     }
 
     fn shrink_to_fit(&mut self) {
-        todo!()
+        self.inner.shrink_to_fit()
     }
 }
@@ -18,6 +18,7 @@ use crate::datatypes::IntervalUnit;
 use crate::datatypes::{DataType, Field, PhysicalType};
 use crate::error::Error;
 use crate::error::Result;
+use crate::io::parquet::union::{contains_union, to_struct_type};
 use crate::types::i256;
 
 mod binary;
@@ -181,9 +182,10 @@ fn make_mutable(data_type: &DataType, capacity: usize) -> Result<Box<dyn Mutable
             Box::new(MutableFixedSizeBinaryArray::try_new(data_type.clone(), vec![], None).unwrap())
                 as _
         }
-        PhysicalType::LargeList | PhysicalType::List => Box::new(
+        PhysicalType::LargeList | PhysicalType::List | PhysicalType::FixedSizeList => Box::new(
             DynMutableListArray::try_with_capacity(data_type.clone(), capacity)?,
-        ) as Box<dyn MutableArray>,
+        )
+            as Box<dyn MutableArray>,
         PhysicalType::Dictionary(_) => Box::new(
             dictionary::DynMutableDictionary::try_with_capacity(data_type.clone(), capacity)?,
         ),
@@ -231,6 +233,12 @@ fn create_dt(data_type: &DataType) -> DataType {
             create_dt(&f.data_type),
             f.is_nullable,
         )))
+    } else if let DataType::FixedSizeList(f, _) = data_type.to_logical_type() {
+        DataType::List(Box::new(Field::new(
+            &f.name,
+            create_dt(&f.data_type),
+            f.is_nullable,
+        )))
     } else {
         DataType::UInt64
     }
@@ -543,16 +551,28 @@ fn push(
         LargeUtf8 => utf8::push::<i64>(from, min, max),
         FixedSizeBinary(_) => fixlen::push(from, min, max),
         Null => null::push(min, max),
-        other => todo!("{:?}", other),
+        other => Err(Error::NotYetImplemented(format!(
+            "Deserializing parquet stats from {other:?} is still not implemented"
+        ))),
     }
 }
 
 /// Deserializes the statistics in the column chunks from all `row_groups`
 /// into [`Statistics`] associated from `field`'s name.
 ///
+/// The statistics of nested types have one statistic per row group for each of their leaves,
+/// nested as the field is: e.g. the statistics of a list are lists of length 1 of those of
+/// its items. The statistics of fixed-size lists are [`ListArray`]s and those of dictionaries
+/// are dictionaries of the statistics of their values.
+/// The statistics of unions are those of the structs of variants they are written as.
 /// # Errors
 /// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
 pub fn deserialize(field: &Field, row_groups: &[RowGroupMetaData]) -> Result<Statistics> {
+    if contains_union(&field.data_type) {
+        let data_type = to_struct_type(&field.data_type);
+        let field = Field::new(field.name.clone(), data_type, field.is_nullable);
+        return deserialize(&field, row_groups);
+    }
     let mut statistics = MutableStatistics::try_new(field)?;
 
     // transpose
@@ -52,14 +52,14 @@ impl MutableArray for DynMutableStructArray {
     }
 
     fn push_null(&mut self) {
-        todo!()
+        self.inner.iter_mut().for_each(|x| x.push_null())
     }
 
-    fn reserve(&mut self, _: usize) {
-        todo!();
+    fn reserve(&mut self, additional: usize) {
+        self.inner.iter_mut().for_each(|x| x.reserve(additional))
     }
 
     fn shrink_to_fit(&mut self) {
-        todo!()
+        self.inner.iter_mut().for_each(|x| x.shrink_to_fit())
     }
 }
@@ -0,0 +1,229 @@
+//! Conversions between unions and the structs of variants and type ids they are stored as.
+use crate::array::{
+    growable::make_growable, Array, FixedSizeListArray, Int8Array, ListArray, MapArray,
+    StructArray, UnionArray,
+};
+use crate::datatypes::{DataType, Field, UnionMode};
+use crate::error::{Error, Result};
+
+/// The name of the last field of the struct of a union, with the type id of each row.
+const TYPE_ID: &str = "__type_id";
+
+/// Whether `data_type` is or contains a [`DataType::Union`].
+pub(crate) fn contains_union(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Union(..) => true,
+        DataType::List(inner)
+        | DataType::LargeList(inner)
+        | DataType::FixedSizeList(inner, _)
+        | DataType::Map(inner, _) => contains_union(&inner.data_type),
+        DataType::Struct(fields) => fields.iter().any(|x| contains_union(&x.data_type)),
+        _ => false,
+    }
+}
+
+fn to_struct_field(field: &Field, is_nullable: bool) -> Field {
+    Field::new(
+        field.name.clone(),
+        to_struct_type(&field.data_type),
+        is_nullable,
+    )
+    .with_metadata(field.metadata.clone())
+}
+
+/// Returns `data_type` with each union replaced by a struct of its variants, all nullable,
+/// followed by the (non-nullable) type ids of its rows.
+pub(crate) fn to_struct_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Union(fields, _, _) => DataType::Struct(
+            fields
+                .iter()
+                .map(|x| to_struct_field(x, true))
+                .chain(std::iter::once(Field::new(TYPE_ID, DataType::Int8, false)))
+                .collect(),
+        ),
+        DataType::List(inner) => {
+            DataType::List(Box::new(to_struct_field(inner, inner.is_nullable)))
+        }
+        DataType::LargeList(inner) => {
+            DataType::LargeList(Box::new(to_struct_field(inner, inner.is_nullable)))
+        }
+        DataType::FixedSizeList(inner, size) => {
+            DataType::FixedSizeList(Box::new(to_struct_field(inner, inner.is_nullable)), *size)
+        }
+        DataType::Map(inner, sorted) => {
+            DataType::Map(Box::new(to_struct_field(inner, inner.is_nullable)), *sorted)
+        }
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|x| to_struct_field(x, x.is_nullable))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+// applies `op` to the children of a nested array, rebuilding it with `data_type`
+fn map_children<F>(array: &dyn Array, data_type: DataType, op: F) -> Result<Box<dyn Array>>
+where
+    F: Fn(&dyn Array, &DataType) -> Result<Box<dyn Array>>,
+{
+    let child_type = |data_type: &DataType, index: usize| match data_type {
+        DataType::List(inner)
+        | DataType::LargeList(inner)
+        | DataType::FixedSizeList(inner, _)
+        | DataType::Map(inner, _) => inner.data_type.clone(),
+        DataType::Struct(fields) => fields[index].data_type.clone(),
+        _ => unreachable!(),
+    };
+    Ok(match array.data_type() {
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = op(array.values().as_ref(), &child_type(&data_type, 0))?;
+            ListArray::<i32>::try_new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = op(array.values().as_ref(), &child_type(&data_type, 0))?;
+            ListArray::<i64>::try_new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = op(array.values().as_ref(), &child_type(&data_type, 0))?;
+            FixedSizeListArray::try_new(data_type, values, array.validity().cloned())?.boxed()
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let field = op(array.field().as_ref(), &child_type(&data_type, 0))?;
+            MapArray::try_new(
+                data_type,
+                array.offsets().clone(),
+                field,
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        DataType::Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .enumerate()
+                .map(|(index, x)| op(x.as_ref(), &child_type(&data_type, index)))
+                .collect::<Result<Vec<_>>>()?;
+            StructArray::try_new(data_type, values, array.validity().cloned())?.boxed()
+        }
+        other => unreachable!("{other:?} has no children"),
+    })
+}
+
+/// Converts `array` to an array of [`to_struct_type`], where the variant field of each row
+/// of a union holds its value, the other fields are null and the last field its type id.
+pub(crate) fn to_struct(array: &dyn Array) -> Result<Box<dyn Array>> {
+    if !contains_union(array.data_type()) {
+        return Ok(array.to_boxed());
+    }
+    let data_type = to_struct_type(array.data_type());
+    let DataType::Union(..) = array.data_type() else {
+        return map_children(array, data_type, |x, _| to_struct(x));
+    };
+
+    let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+    let len = array.len();
+    let values = array
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(field, values)| {
+            let mut growable = make_growable(&[values.as_ref()], true, len);
+            (0..len).for_each(|index| match array.index(index) {
+                (type_, slot) if type_ == field => growable.extend(0, slot, 1),
+                _ => growable.extend_validity(1),
+            });
+            to_struct(growable.as_box().as_ref())
+        })
+        .chain(std::iter::once(Ok(Int8Array::new(
+            DataType::Int8,
+            array.types().clone(),
+            None,
+        )
+        .boxed())))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(StructArray::try_new(data_type, values, None)?.boxed())
+}
+
+/// Converts `array`, of [`to_struct_type`] of `data_type`, back to `data_type`.
+/// # Errors
+/// Errors iff a type id is not one of its union's.
+pub(crate) fn from_struct(array: &dyn Array, data_type: &DataType) -> Result<Box<dyn Array>> {
+    if !contains_union(data_type) {
+        return Ok(array.to_boxed());
+    }
+    let DataType::Union(fields, ids, mode) = data_type else {
+        return map_children(array, data_type.clone(), from_struct);
+    };
+
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    let (types, values) = array.values().split_last().unwrap();
+    let types = types.as_any().downcast_ref::<Int8Array>().unwrap();
+    let values = values
+        .iter()
+        .zip(fields)
+        .map(|(values, field)| from_struct(values.as_ref(), &field.data_type))
+        .collect::<Result<Vec<_>>>()?;
+
+    let variants = types
+        .values()
+        .iter()
+        .map(|type_| {
+            let type_ = *type_ as i32;
+            ids.as_ref()
+                .map_or_else(
+                    || usize::try_from(type_).ok().filter(|x| *x < fields.len()),
+                    |ids| ids.iter().position(|id| *id == type_),
+                )
+                .ok_or_else(|| Error::oos(format!("The type id {type_} is not one of the union's")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (values, offsets) = match mode {
+        UnionMode::Sparse => (values, None),
+        UnionMode::Dense => {
+            let mut offsets = Vec::with_capacity(variants.len());
+            let mut lengths = vec![0i32; values.len()];
+            for variant in &variants {
+                offsets.push(lengths[*variant]);
+                lengths[*variant] += 1;
+            }
+            let values = values
+                .iter()
+                .enumerate()
+                .map(|(field, values)| {
+                    let mut growable =
+                        make_growable(&[values.as_ref()], false, lengths[field] as usize);
+                    variants
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, variant)| **variant == field)
+                        .for_each(|(index, _)| growable.extend(0, index, 1));
+                    growable.as_box()
+                })
+                .collect();
+            (values, Some(offsets.into()))
+        }
+    };
+    Ok(UnionArray::try_new(data_type.clone(), types.values().clone(), values, offsets)?.boxed())
+}
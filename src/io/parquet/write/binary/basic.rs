@@ -40,6 +40,18 @@ pub(crate) fn encode_plain<O: Offset>(
     }
 }
 
+pub(crate) fn encode_delta_byte_array<O: Offset>(
+    array: &BinaryArray<O>,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) {
+    if is_optional {
+        encode_prefixed(array.iter().flatten(), buffer)
+    } else {
+        encode_prefixed(array.values_iter(), buffer)
+    }
+}
+
 pub fn array_to_page<O: Offset>(
     array: &BinaryArray<O>,
     options: WriteOptions,
@@ -69,6 +81,7 @@ pub fn array_to_page<O: Offset>(
             is_optional,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => encode_delta_byte_array(array, is_optional, &mut buffer),
         _ => {
             return Err(Error::InvalidArgumentError(format!(
                 "Datatype {:?} cannot be encoded by {:?} encoding",
@@ -152,6 +165,30 @@ pub(crate) fn encode_delta<O: Offset>(
     )
 }
 
+/// Encodes `values` as `DELTA_BYTE_ARRAY`: the length of the prefix each value shares with the
+/// previous value, followed by the remaining suffixes encoded as `DELTA_LENGTH_BYTE_ARRAY`.
+pub(crate) fn encode_prefixed<'a, I: Iterator<Item = &'a [u8]>>(values: I, buffer: &mut Vec<u8>) {
+    let mut previous: &[u8] = &[];
+    let mut prefixes = vec![];
+    let mut lengths = vec![];
+    let mut suffixes = vec![];
+    for value in values {
+        let prefix = value
+            .iter()
+            .zip(previous.iter())
+            .take_while(|(lhs, rhs)| lhs == rhs)
+            .count();
+        prefixes.push(prefix as i64);
+        lengths.push((value.len() - prefix) as i64);
+        suffixes.extend_from_slice(&value[prefix..]);
+        previous = value;
+    }
+
+    delta_bitpacked::encode(prefixes.into_iter(), buffer);
+    delta_bitpacked::encode(lengths.into_iter(), buffer);
+    buffer.extend_from_slice(&suffixes);
+}
+
 /// Returns the ordering of two binary values. This corresponds to pyarrows' ordering
 /// of statistics.
 pub(crate) fn ord_binary<'a>(a: &'a [u8], b: &'a [u8]) -> std::cmp::Ordering {
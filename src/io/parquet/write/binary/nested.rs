@@ -2,7 +2,7 @@ use parquet2::schema::types::PrimitiveType;
 use parquet2::{encoding::Encoding, page::DataPage};
 
 use super::super::{nested, utils, WriteOptions};
-use super::basic::{build_statistics, encode_plain};
+use super::basic::{build_statistics, encode_delta_byte_array, encode_plain};
 use crate::io::parquet::read::schema::is_nullable;
 use crate::io::parquet::write::Nested;
 use crate::{
@@ -16,6 +16,7 @@ pub fn array_to_page<O>(
     options: WriteOptions,
     type_: PrimitiveType,
     nested: &[Nested],
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     O: Offset,
@@ -26,7 +27,17 @@ where
     let (repetition_levels_byte_length, definition_levels_byte_length) =
         nested::write_rep_and_def(options.version, nested, &mut buffer)?;
 
-    encode_plain(array, is_optional, &mut buffer);
+    // encodings other than `DeltaByteArray` are not yet supported for nested types
+    let encoding = match encoding {
+        Encoding::DeltaByteArray => {
+            encode_delta_byte_array(array, is_optional, &mut buffer);
+            encoding
+        }
+        _ => {
+            encode_plain(array, is_optional, &mut buffer);
+            Encoding::Plain
+        }
+    };
 
     let statistics = if options.write_statistics {
         Some(build_statistics(array, type_.clone()))
@@ -44,6 +55,6 @@ where
         statistics,
         type_,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
@@ -0,0 +1,502 @@
+//! Split-block bloom filters of column chunks.
+use std::io::{Cursor, Write};
+use std::sync::{Arc, Mutex};
+
+use parquet2::bloom_filter::{hash_byte, hash_native, insert};
+use parquet2::compression::Compression;
+use parquet2::metadata::SchemaDescriptor;
+use parquet2::page::CompressedPage;
+use parquet2::read::{BasicDecompressor, PageMetaData, PageReader};
+use parquet2::types::NativeType as ParquetNativeType;
+use parquet2::write::{DynIter, DynStreamingIterator, RowGroupIter};
+use parquet2::FallibleStreamingIterator;
+use parquet_format_safe::{
+    thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol},
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::{
+    array::{
+        new_empty_array, Array, BinaryArray, DictionaryArray, FixedSizeBinaryArray, PrimitiveArray,
+        Utf8Array,
+    },
+    datatypes::{DataType, Schema},
+    error::{Error, Result},
+    io::parquet::{read::column_iter_to_arrays, read::n_columns, union::to_struct},
+    offset::Offset,
+    types::NativeType,
+};
+
+use super::{to_leaves, transverse, write_metadata_sidecar, ThriftFileMetaData};
+
+/// The minimum size of a bitset, one block of 8 words
+const MIN_BYTES: usize = 32;
+/// The maximum size of a bitset, as used by parquet-mr
+const MAX_BYTES: usize = 128 * 1024 * 1024;
+
+/// Options of the split-block bloom filter written for a column chunk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The target false positive probability, in `(0, 1)`
+    pub fpp: f64,
+    /// The estimated number of distinct values of the column chunk
+    pub ndv: u64,
+}
+
+impl BloomFilterOptions {
+    /// Returns the size in bytes of a bitset with a false positive probability of at most
+    /// `fpp` for `ndv` distinct values, rounded up to a power of two.
+    pub fn num_bytes(&self) -> usize {
+        // the spec's `m = -k * ndv / ln(1 - fpp^(1 / k))` bits for `k = 8` hashes per value
+        let bits = -8.0 * self.ndv as f64 / (1.0 - self.fpp.powf(1.0 / 8.0)).ln();
+        ((bits / 8.0).ceil() as usize)
+            .next_power_of_two()
+            .clamp(MIN_BYTES, MAX_BYTES)
+    }
+
+    pub(super) fn check(&self) -> Result<()> {
+        if self.fpp > 0.0 && self.fpp < 1.0 {
+            Ok(())
+        } else {
+            Err(Error::InvalidArgumentError(format!(
+                "The false positive probability of a bloom filter must be in (0, 1), but is {}",
+                self.fpp
+            )))
+        }
+    }
+}
+
+fn hash_primitive<T, P, F>(array: &dyn Array, f: &mut F)
+where
+    T: NativeType + num_traits::AsPrimitive<P>,
+    P: ParquetNativeType,
+    F: FnMut(u64),
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .iter()
+        .flatten()
+        .for_each(|x| f(hash_native::<P>(x.as_())));
+}
+
+fn hash_bytes<'a, I: Iterator<Item = &'a [u8]>, F: FnMut(u64)>(values: I, f: &mut F) {
+    values.for_each(|x| f(hash_byte(x)));
+}
+
+fn hash_utf8<O: Offset, F: FnMut(u64)>(array: &dyn Array, f: &mut F) {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    hash_bytes(array.iter().flatten().map(|x| x.as_bytes()), f)
+}
+
+fn hash_binary<O: Offset, F: FnMut(u64)>(array: &dyn Array, f: &mut F) {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    hash_bytes(array.iter().flatten(), f)
+}
+
+/// Calls `f` with the hash of each valid value of `array`, hashed as its parquet physical
+/// (plain-encoded) value.
+/// # Errors
+/// Errors iff bloom filters do not support the type of `array`.
+// casts below MUST match the casts done when writing pages.
+pub(crate) fn hash_array<F: FnMut(u64)>(array: &dyn Array, f: &mut F) -> Result<()> {
+    match array.data_type().to_logical_type() {
+        DataType::UInt8 => hash_primitive::<u8, i32, _>(array, f),
+        DataType::UInt16 => hash_primitive::<u16, i32, _>(array, f),
+        DataType::UInt32 => hash_primitive::<u32, i32, _>(array, f),
+        DataType::UInt64 => hash_primitive::<u64, i64, _>(array, f),
+        DataType::Int8 => hash_primitive::<i8, i32, _>(array, f),
+        DataType::Int16 => hash_primitive::<i16, i32, _>(array, f),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            hash_primitive::<i32, i32, _>(array, f)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => hash_primitive::<i64, i64, _>(array, f),
+        DataType::Float32 => hash_primitive::<f32, f32, _>(array, f),
+        DataType::Float64 => hash_primitive::<f64, f64, _>(array, f),
+        DataType::Decimal(precision, _) if *precision <= 9 => {
+            hash_primitive::<i128, i32, _>(array, f)
+        }
+        DataType::Decimal(precision, _) if *precision <= 18 => {
+            hash_primitive::<i128, i64, _>(array, f)
+        }
+        DataType::Utf8 => hash_utf8::<i32, _>(array, f),
+        DataType::LargeUtf8 => hash_utf8::<i64, _>(array, f),
+        DataType::Binary => hash_binary::<i32, _>(array, f),
+        DataType::LargeBinary => hash_binary::<i64, _>(array, f),
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            hash_bytes(array.iter().flatten(), f)
+        }
+        DataType::Dictionary(key_type, _, _) => {
+            return match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                hash_array(array.values().as_ref(), f)
+            })
+        }
+        other => {
+            return Err(Error::InvalidArgumentError(format!(
+                "Bloom filters cannot be built for columns of type {other:?}"
+            )))
+        }
+    };
+    Ok(())
+}
+
+/// The options of the bloom filter of each parquet column of a file and the bitsets of the
+/// row groups written to it.
+#[derive(Debug, Default)]
+pub(super) struct BloomFilters {
+    options: Vec<Option<BloomFilterOptions>>,
+    // the bitsets of each column of each written row group
+    bitsets: Vec<Vec<Option<Vec<u8>>>>,
+}
+
+impl BloomFilters {
+    /// Sets the options of the bloom filter of each parquet column of `schema`.
+    /// # Errors
+    /// Errors iff the number of options differs from the number of parquet columns, a false
+    /// positive probability is not in `(0, 1)` or a column's type is not supported.
+    pub fn set(&mut self, schema: &Schema, options: Vec<Option<BloomFilterOptions>>) -> Result<()> {
+        let types = schema
+            .fields
+            .iter()
+            .flat_map(|x| transverse(&x.data_type, |x| x.clone()))
+            .collect::<Vec<_>>();
+        if options.len() != types.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The number of bloom filter options ({}) must equal the number of parquet columns ({})",
+                options.len(),
+                types.len()
+            )));
+        }
+        options
+            .iter()
+            .zip(types)
+            .filter_map(|(options, data_type)| options.map(|x| (x, data_type)))
+            .try_for_each(|(options, data_type)| {
+                options.check()?;
+                hash_array(new_empty_array(data_type).as_ref(), &mut |_| {})
+            })?;
+        self.options = options;
+        Ok(())
+    }
+
+    /// Whether a bloom filter is written for any column.
+    pub fn is_enabled(&self) -> bool {
+        self.options.iter().any(|x| x.is_some())
+    }
+
+    /// Whether a bloom filter was built for any column chunk.
+    pub fn has_bitsets(&self) -> bool {
+        self.bitsets.iter().flatten().any(|x| x.is_some())
+    }
+
+    fn new_bitsets(&self) -> Vec<Option<Vec<u8>>> {
+        self.options
+            .iter()
+            .map(|x| x.map(|x| vec![0; x.num_bytes()]))
+            .collect()
+    }
+
+    /// Pushes a row group without bloom filters.
+    pub fn push_empty(&mut self) {
+        self.bitsets.push(vec![None; self.options.len()]);
+    }
+
+    /// Pushes the bloom filters of `chunk`, written as a row group.
+    #[cfg(feature = "io_parquet_async")]
+    pub fn push_chunk<A: AsRef<dyn Array>>(
+        &mut self,
+        chunk: &crate::chunk::Chunk<A>,
+    ) -> Result<()> {
+        let mut bitsets = self.new_bitsets();
+        if self.is_enabled() {
+            let arrays = chunk
+                .arrays()
+                .iter()
+                .map(|x| to_struct(x.as_ref()))
+                .collect::<Result<Vec<_>>>()?;
+            let leaves = arrays.iter().flat_map(|x| to_leaves(x.as_ref()));
+            insert_leaves(leaves, &mut bitsets)?;
+        }
+        self.bitsets.push(bitsets);
+        Ok(())
+    }
+
+    /// Pushes the bloom filters of a row group of `schema` by reading its `columns` back
+    /// from `bytes`, the bytes written for it.
+    pub fn push_pages(
+        &mut self,
+        bytes: &[u8],
+        columns: &[ColumnPages],
+        parquet_schema: &SchemaDescriptor,
+        schema: &Schema,
+    ) -> Result<()> {
+        let mut bitsets = self.new_bitsets();
+        let mut start = 0;
+        for field in &schema.fields {
+            let end = start + n_columns(&field.data_type);
+            if bitsets[start..end].iter().any(|x| x.is_some()) {
+                let descriptors = &parquet_schema.columns()[start..end];
+                let pages = columns[start..end]
+                    .iter()
+                    .zip(descriptors)
+                    .map(|(column, descriptor)| {
+                        let meta = PageMetaData::new(
+                            column.start as u64,
+                            column.num_values as i64,
+                            column.compression,
+                            descriptor.descriptor.clone(),
+                        );
+                        // the reader must start at the pages
+                        let reader = Cursor::new(&bytes[column.start..]);
+                        let pages = PageReader::new_with_page_meta(
+                            reader,
+                            meta,
+                            Arc::new(|_, _| true),
+                            vec![],
+                            usize::MAX,
+                        );
+                        BasicDecompressor::new(pages, vec![])
+                    })
+                    .collect();
+                let types = descriptors
+                    .iter()
+                    .map(|x| &x.descriptor.primitive_type)
+                    .collect();
+                let num_rows = columns[start].num_rows;
+                for array in column_iter_to_arrays(pages, types, field.clone(), None, num_rows)? {
+                    let array = to_struct(array?.as_ref())?;
+                    insert_leaves(to_leaves(array.as_ref()), &mut bitsets[start..end])?;
+                }
+            }
+            start = end;
+        }
+        self.bitsets.push(bitsets);
+        Ok(())
+    }
+
+    /// Returns the bytes that replace `captured`, the page indexes and footer written at the
+    /// end of a file of `size` bytes, so that the bloom filters are written between them,
+    /// together with the new metadata and size of the file.
+    pub fn replace_footer(
+        &self,
+        captured: &[u8],
+        size: u64,
+    ) -> Result<(Vec<u8>, ThriftFileMetaData, u64)> {
+        // the footer is the metadata, its length (4 bytes) and the magic (4 bytes)
+        let metadata_len = captured[captured.len() - 8..captured.len() - 4]
+            .try_into()
+            .map(i32::from_le_bytes)
+            .unwrap() as usize;
+        let metadata_start = captured.len() - 8 - metadata_len;
+        let mut protocol = TCompactInputProtocol::new(&captured[metadata_start..], metadata_len);
+        let mut metadata = ThriftFileMetaData::read_from_in_protocol(&mut protocol)
+            .map_err(parquet2::error::Error::from)?;
+
+        let mut bytes = captured[..metadata_start].to_vec();
+        let mut offset = size - captured.len() as u64 + metadata_start as u64;
+        for (group, bitsets) in metadata.row_groups.iter_mut().zip(&self.bitsets) {
+            for (column, bitset) in group.columns.iter_mut().zip(bitsets) {
+                if let (Some(bitset), Some(column)) = (bitset, column.meta_data.as_mut()) {
+                    column.bloom_filter_offset = Some(offset as i64);
+                    offset += write(&mut bytes, bitset)?;
+                }
+            }
+        }
+
+        // a sidecar is the magic followed by the footer
+        let mut footer = vec![];
+        write_metadata_sidecar(&mut footer, &metadata)?;
+        bytes.extend_from_slice(&footer[4..]);
+        Ok((bytes, metadata, offset + footer.len() as u64 - 4))
+    }
+}
+
+// inserts the valid values of each leaf column into its bitset, if any
+fn insert_leaves<'a, I: IntoIterator<Item = &'a dyn Array>>(
+    leaves: I,
+    bitsets: &mut [Option<Vec<u8>>],
+) -> Result<()> {
+    leaves
+        .into_iter()
+        .zip(bitsets.iter_mut())
+        .filter_map(|(leaf, bitset)| bitset.as_mut().map(|x| (leaf, x)))
+        .try_for_each(|(leaf, bitset)| hash_array(leaf, &mut |hash| insert(bitset, hash)))
+}
+
+/// Writes the header and `bitset` of a bloom filter to `writer`, returning the number of
+/// bytes written.
+fn write<W: Write>(writer: &mut W, bitset: &[u8]) -> Result<u64> {
+    let header = BloomFilterHeader::new(
+        bitset.len() as i32,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    );
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let header_len = header
+        .write_to_out_protocol(&mut protocol)
+        .map_err(parquet2::error::Error::from)?;
+    writer.write_all(bitset)?;
+    Ok((header_len + bitset.len()) as u64)
+}
+
+/// The position of the pages of a column chunk in the bytes written for its row group
+#[derive(Debug, Clone)]
+pub(super) struct ColumnPages {
+    start: usize,
+    num_values: usize,
+    num_rows: usize,
+    compression: Compression,
+}
+
+/// The bytes written while copying, shared with the [`ColumnPages`] being tracked
+pub(super) type Copied = Arc<Mutex<Option<Vec<u8>>>>;
+
+/// Returns `row_group` with its [`ColumnPages`] pushed to `columns` while it is written.
+pub(super) fn track(
+    row_group: RowGroupIter<'_, Error>,
+    copied: Copied,
+    columns: Arc<Mutex<Vec<ColumnPages>>>,
+) -> RowGroupIter<'_, Error> {
+    DynIter::new(row_group.map(move |pages| {
+        pages.map(|pages| {
+            DynStreamingIterator::new(TrackedPages {
+                pages,
+                copied: copied.clone(),
+                columns: columns.clone(),
+                index: None,
+            })
+        })
+    }))
+}
+
+struct TrackedPages<'a> {
+    pages: DynStreamingIterator<'a, CompressedPage, Error>,
+    copied: Copied,
+    columns: Arc<Mutex<Vec<ColumnPages>>>,
+    index: Option<usize>,
+}
+
+impl<'a> FallibleStreamingIterator for TrackedPages<'a> {
+    type Item = CompressedPage;
+    type Error = Error;
+
+    fn advance(&mut self) -> Result<()> {
+        let mut columns = self.columns.lock().unwrap();
+        // the pages of a column are written after those of the previous columns
+        let index = *self.index.get_or_insert_with(|| {
+            let copied = self.copied.lock().unwrap();
+            columns.push(ColumnPages {
+                start: copied.as_ref().map_or(0, |x| x.len()),
+                num_values: 0,
+                num_rows: 0,
+                compression: Compression::Uncompressed,
+            });
+            columns.len() - 1
+        });
+        self.pages.advance()?;
+        let column = &mut columns[index];
+        match self.pages.get() {
+            Some(CompressedPage::Data(page)) => {
+                column.compression = page.compression();
+                column.num_values += page.num_values();
+                column.num_rows += page
+                    .selected_rows()
+                    .map_or(0, |x| x.iter().map(|x| x.length).sum());
+            }
+            Some(CompressedPage::Dict(page)) => column.compression = page.compression(),
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.pages.get()
+    }
+}
+
+/// The state shared by a [`SharedWriter`] and the writer of the file using it
+#[derive(Debug)]
+pub(super) struct Shared<W> {
+    pub writer: W,
+    /// The bytes written while capturing instead of being written to `writer`
+    pub captured: Option<Vec<u8>>,
+    /// The bytes written while copying, also written to `writer`
+    pub copied: Copied,
+}
+
+impl<W> Shared<W> {
+    pub fn new(writer: W) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            writer,
+            captured: None,
+            copied: Arc::new(Mutex::new(None)),
+        }))
+    }
+}
+
+/// A writer given to the writers of parquet2 that can capture the footer they write, so that
+/// bloom filters can be written before it, and copy the row groups they write.
+#[derive(Debug)]
+pub(super) struct SharedWriter<W>(pub Arc<Mutex<Shared<W>>>);
+
+impl<W: Write> Write for SharedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut shared = self.0.lock().unwrap();
+        if let Some(captured) = shared.captured.as_mut() {
+            captured.extend_from_slice(buf);
+            return Ok(buf.len());
+        }
+        let written = shared.writer.write(buf)?;
+        if let Some(copied) = shared.copied.lock().unwrap().as_mut() {
+            copied.extend_from_slice(&buf[..written]);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.lock().unwrap().writer.flush()
+    }
+}
+
+#[cfg(feature = "io_parquet_async")]
+impl<W: futures::AsyncWrite + Unpin> futures::AsyncWrite for SharedWriter<W> {
+    fn poll_write(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        let mut shared = self.0.lock().unwrap();
+        if let Some(captured) = shared.captured.as_mut() {
+            captured.extend_from_slice(buf);
+            return std::task::Poll::Ready(Ok(buf.len()));
+        }
+        std::pin::Pin::new(&mut shared.writer).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::pin::Pin::new(&mut self.0.lock().unwrap().writer).poll_flush(cx)
+    }
+
+    fn poll_close(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::pin::Pin::new(&mut self.0.lock().unwrap().writer).poll_close(cx)
+    }
+}
@@ -6,12 +6,23 @@ use parquet2::{
     write::DynIter,
 };
 
-use crate::io::parquet::write::{slice_nested_leaf, utils};
+use hashbrown::hash_map::{Entry, HashMap};
+
+use crate::compute::aggregate::estimated_bytes_size;
+use crate::io::parquet::write::{rows_per_page, slice_parquet_array, utils};
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey},
+    array::{
+        growable::make_growable, Array, BinaryArray, DictionaryArray, DictionaryKey,
+        FixedSizeBinaryArray, PrimitiveArray, Utf8Array,
+    },
     io::parquet::read::schema::is_nullable,
+    offset::Offset,
+    types::NativeType,
+};
+use crate::{
+    bitmap::Bitmap,
+    datatypes::{DataType, IntegerType},
 };
-use crate::{bitmap::Bitmap, datatypes::DataType};
 use crate::{
     bitmap::MutableBitmap,
     error::{Error, Result},
@@ -27,6 +38,122 @@ use super::WriteOptions;
 use super::{binary::build_statistics as binary_build_statistics, Nested};
 use super::{binary::encode_plain as binary_encode_plain, nested};
 
+/// Dictionary-encodes `array` by the bytes returned by `value` for each valid slot, or returns
+/// `None` if the plain-encoded dictionary (`overhead` bytes per value plus its bytes) would
+/// exceed `max_size` bytes.
+fn encode_by<'a, F: Fn(usize) -> &'a [u8]>(
+    array: &dyn Array,
+    value: F,
+    overhead: usize,
+    max_size: usize,
+) -> Option<DictionaryArray<u32>> {
+    let validity = array.validity();
+
+    let mut map = HashMap::<&'a [u8], u32>::new();
+    let mut firsts = vec![];
+    let mut size = 0;
+    let mut keys = Vec::<u32>::with_capacity(array.len());
+    for index in 0..array.len() {
+        if validity.map_or(false, |validity| !validity.get_bit(index)) {
+            keys.push(0);
+            continue;
+        }
+        let value = value(index);
+        let key = match map.entry(value) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                size += value.len() + overhead;
+                if size > max_size {
+                    return None;
+                }
+                firsts.push(index);
+                *entry.insert(firsts.len() as u32 - 1)
+            }
+        };
+        keys.push(key);
+    }
+
+    let mut growable = make_growable(&[array], false, firsts.len());
+    firsts
+        .iter()
+        .for_each(|index| growable.extend(0, *index, 1));
+    let values = growable.as_box().with_validity(None);
+
+    let keys = PrimitiveArray::new(DataType::UInt32, keys.into(), validity.cloned());
+    let data_type = DataType::Dictionary(
+        IntegerType::UInt32,
+        Box::new(array.data_type().clone()),
+        false,
+    );
+    Some(DictionaryArray::try_new(data_type, keys, values).unwrap())
+}
+
+fn encode_primitive<T: NativeType>(
+    array: &dyn Array,
+    max_size: usize,
+) -> Option<DictionaryArray<u32>> {
+    let values = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    let bytes: &[u8] = bytemuck::cast_slice(values.as_slice());
+    let size = std::mem::size_of::<T>();
+    encode_by(
+        array,
+        |index| &bytes[index * size..(index + 1) * size],
+        0,
+        max_size,
+    )
+}
+
+fn encode_utf8<O: Offset>(array: &dyn Array, max_size: usize) -> Option<DictionaryArray<u32>> {
+    let utf8 = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    encode_by(array, |index| utf8.value(index).as_bytes(), 4, max_size)
+}
+
+fn encode_binary<O: Offset>(array: &dyn Array, max_size: usize) -> Option<DictionaryArray<u32>> {
+    let binary = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    encode_by(array, |index| binary.value(index), 4, max_size)
+}
+
+/// Dictionary-encodes a non-dictionary `array`, or returns `None` if its dictionary page
+/// would exceed `max_size` bytes.
+/// # Panics
+/// Panics iff `array` cannot be dictionary-encoded (see [`super::can_encode`]).
+pub fn encode(array: &dyn Array, max_size: usize) -> Option<DictionaryArray<u32>> {
+    match array.data_type().to_logical_type() {
+        DataType::Int8 => encode_primitive::<i8>(array, max_size),
+        DataType::Int16 => encode_primitive::<i16>(array, max_size),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            encode_primitive::<i32>(array, max_size)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => encode_primitive::<i64>(array, max_size),
+        DataType::UInt8 => encode_primitive::<u8>(array, max_size),
+        DataType::UInt16 => encode_primitive::<u16>(array, max_size),
+        DataType::UInt32 => encode_primitive::<u32>(array, max_size),
+        DataType::UInt64 => encode_primitive::<u64>(array, max_size),
+        DataType::Float32 => encode_primitive::<f32>(array, max_size),
+        DataType::Float64 => encode_primitive::<f64>(array, max_size),
+        DataType::Utf8 => encode_utf8::<i32>(array, max_size),
+        DataType::LargeUtf8 => encode_utf8::<i64>(array, max_size),
+        DataType::Binary => encode_binary::<i32>(array, max_size),
+        DataType::LargeBinary => encode_binary::<i64>(array, max_size),
+        DataType::FixedSizeBinary(_) => {
+            let binary = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            encode_by(array, |index| binary.value(index), 0, max_size)
+        }
+        other => unreachable!("{other:?} cannot be dictionary-encoded"),
+    }
+}
+
 fn serialize_def_levels_simple(
     validity: Option<&Bitmap>,
     length: usize,
@@ -100,6 +227,8 @@ fn normalized_validity<K: DictionaryKey>(array: &DictionaryArray<K>) -> Option<B
     }
 }
 
+/// Serializes the keys of `array`, the leaf values of `nested` (e.g. as sliced by
+/// [`slice_parquet_array`]), into a data page.
 fn serialize_keys<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     type_: PrimitiveType,
@@ -112,31 +241,22 @@ fn serialize_keys<K: DictionaryKey>(
     // parquet only accepts a single validity - we "&" the validities into a single one
     // and ignore keys whole _value_ is null.
     let validity = normalized_validity(array);
-    let (start, len) = slice_nested_leaf(nested);
-
-    let mut nested = nested.to_vec();
-    let array = array.clone().sliced(start, len);
-    if let Some(Nested::Primitive(_, _, c)) = nested.last_mut() {
-        *c = len;
-    } else {
-        unreachable!("")
-    }
 
     let (repetition_levels_byte_length, definition_levels_byte_length) = serialize_levels(
         validity.as_ref(),
         array.len(),
         &type_,
-        &nested,
+        nested,
         options,
         &mut buffer,
     )?;
 
-    serialize_keys_values(&array, validity.as_ref(), &mut buffer)?;
+    serialize_keys_values(array, validity.as_ref(), &mut buffer)?;
 
     let (num_values, num_rows) = if nested.len() == 1 {
         (array.len(), array.len())
     } else {
-        (nested::num_values(&nested), nested[0].len())
+        (nested::num_values(nested), nested[0].len())
     };
 
     utils::build_plain_page(
@@ -252,16 +372,16 @@ pub fn array_to_pages<K: DictionaryKey>(
                     }
                     DataType::FixedSizeBinary(_) => {
                         let mut buffer = vec![];
-                        let array = array.values().as_any().downcast_ref().unwrap();
-                        fixed_binary_encode_plain(array, false, &mut buffer);
+                        let values = array.values().as_any().downcast_ref().unwrap();
+                        fixed_binary_encode_plain(values, false, &mut buffer);
                         let stats = if options.write_statistics {
-                            let mut stats = fixed_binary_build_statistics(array, type_.clone());
+                            let mut stats = fixed_binary_build_statistics(values, type_.clone());
                             stats.null_count = Some(array.null_count() as i64);
                             Some(serialize_statistics(&stats))
                         } else {
                             None
                         };
-                        (DictPage::new(buffer, array.len(), false), stats)
+                        (DictPage::new(buffer, values.len(), false), stats)
                     }
                     other => {
                         return Err(Error::NotYetImplemented(format!(
@@ -271,10 +391,36 @@ pub fn array_to_pages<K: DictionaryKey>(
                 };
             let dict_page = Page::Dict(dict_page);
 
-            // write DataPage pointing to DictPage
-            let data_page = serialize_keys(array, type_, nested, statistics, options)?;
+            // write DataPages pointing to DictPage
+            let nested = nested.to_vec();
+            let number_of_rows = nested[0].len();
+            let byte_size = estimated_bytes_size(array.keys());
+            let rows_per_page = rows_per_page(byte_size, number_of_rows, options);
+            let array = array.clone();
+
+            // a column chunk without rows still has a data page
+            let data_pages = (0..number_of_rows.max(1))
+                .step_by(rows_per_page)
+                .map(move |offset| {
+                    let length = rows_per_page.min(number_of_rows - offset);
+                    let mut right_array = array.clone();
+                    let mut right_nested = nested.clone();
+                    slice_parquet_array(&mut right_array, &mut right_nested, offset, length);
+
+                    let statistics = statistics.clone().map(|mut statistics| {
+                        statistics.null_count = Some(right_array.null_count() as i64);
+                        statistics
+                    });
+                    serialize_keys(
+                        &right_array,
+                        type_.clone(),
+                        &right_nested,
+                        statistics,
+                        options,
+                    )
+                });
 
-            let iter = std::iter::once(Ok(dict_page)).chain(std::iter::once(Ok(data_page)));
+            let iter = std::iter::once(Ok(dict_page)).chain(data_pages);
             Ok(DynIter::new(Box::new(iter)))
         }
         _ => Err(Error::NotYetImplemented(
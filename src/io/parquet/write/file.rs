@@ -8,7 +8,11 @@ use parquet2::write::WriteOptions as FileWriteOptions;
 use crate::datatypes::Schema;
 use crate::error::{Error, Result};
 
+#[cfg(feature = "io_parquet_bloom_filter")]
+use super::bloom_filter::{self, BloomFilterOptions, BloomFilters, Shared, SharedWriter};
 use super::{schema::schema_to_metadata_key, to_parquet_schema, ThriftFileMetaData, WriteOptions};
+#[cfg(feature = "io_parquet_bloom_filter")]
+use std::sync::{Arc, Mutex};
 
 /// Attaches [`Schema`] to `key_value_metadata`
 pub fn add_arrow_schema(
@@ -25,9 +29,23 @@ pub fn add_arrow_schema(
 
 /// An interface to write a parquet to a [`Write`]
 pub struct FileWriter<W: Write> {
+    #[cfg(not(feature = "io_parquet_bloom_filter"))]
     writer: parquet2::write::FileWriter<W>,
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    writer: parquet2::write::FileWriter<SharedWriter<W>>,
     schema: Schema,
     options: WriteOptions,
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    bloom: BloomState<W>,
+}
+
+/// The state of [`FileWriter`] required to write bloom filters
+#[cfg(feature = "io_parquet_bloom_filter")]
+struct BloomState<W: Write> {
+    shared: Arc<Mutex<Shared<W>>>,
+    filters: BloomFilters,
+    // the metadata of the file, including the offsets of the bloom filters
+    metadata: Option<ThriftFileMetaData>,
 }
 
 // Accessors
@@ -57,6 +75,15 @@ impl<W: Write> FileWriter<W> {
 
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
 
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        let bloom = BloomState {
+            shared: Shared::new(writer),
+            filters: BloomFilters::default(),
+            metadata: None,
+        };
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        let writer = SharedWriter(bloom.shared.clone());
+
         Ok(Self {
             writer: parquet2::write::FileWriter::new(
                 writer,
@@ -69,29 +96,118 @@ impl<W: Write> FileWriter<W> {
             ),
             schema,
             options,
+            #[cfg(feature = "io_parquet_bloom_filter")]
+            bloom,
         })
     }
 
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIter<'_, Error>) -> Result<()> {
-        Ok(self.writer.write(row_group)?)
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        if self.bloom.filters.is_enabled() {
+            return self.write_with_bloom_filters(row_group);
+        }
+        self.writer.write(row_group)?;
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        self.bloom.filters.push_empty();
+        Ok(())
     }
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        if self.bloom.filters.has_bitsets() {
+            return self.end_with_bloom_filters(key_value_metadata);
+        }
         Ok(self.writer.end(key_value_metadata)?)
     }
 
     /// Consumes this writer and returns the inner writer
     pub fn into_inner(self) -> W {
-        self.writer.into_inner()
+        self.into_inner_and_metadata_opt().0
     }
 
     /// Returns the underlying writer and [`ThriftFileMetaData`]
     /// # Panics
     /// This function panics if [`Self::end`] has not yet been called
     pub fn into_inner_and_metadata(self) -> (W, ThriftFileMetaData) {
-        self.writer.into_inner_and_metadata()
+        let (writer, metadata) = self.into_inner_and_metadata_opt();
+        (writer, metadata.expect("File to have ended"))
+    }
+
+    #[cfg(not(feature = "io_parquet_bloom_filter"))]
+    fn into_inner_and_metadata_opt(self) -> (W, Option<ThriftFileMetaData>) {
+        let metadata = self.writer.metadata().cloned();
+        (self.writer.into_inner(), metadata)
+    }
+
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    fn into_inner_and_metadata_opt(self) -> (W, Option<ThriftFileMetaData>) {
+        let metadata = self
+            .bloom
+            .metadata
+            .or_else(|| self.writer.metadata().cloned());
+        // the writer of parquet2 holds the only other reference to the shared state
+        drop(self.writer);
+        let shared = Arc::try_unwrap(self.bloom.shared)
+            .unwrap_or_else(|_| unreachable!())
+            .into_inner()
+            .unwrap();
+        (shared.writer, metadata)
+    }
+}
+
+#[cfg(feature = "io_parquet_bloom_filter")]
+impl<W: Write> FileWriter<W> {
+    /// Sets the options of the bloom filter of each parquet column of the file (in the order
+    /// of [`Self::parquet_schema`]'s columns), where `None` writes no bloom filter. They apply
+    /// to the row groups written afterwards.
+    ///
+    /// The bloom filters of a row group are built by reading back the column chunks written
+    /// by [`Self::write`], which are kept in memory until the row group is written.
+    /// # Errors
+    /// Errors iff the number of options differs from the number of parquet columns, a false
+    /// positive probability is not in `(0, 1)` or a bloom filter was requested for a column of
+    /// an unsupported type.
+    pub fn set_bloom_filters(&mut self, options: Vec<Option<BloomFilterOptions>>) -> Result<()> {
+        self.bloom.filters.set(&self.schema, options)
+    }
+
+    fn write_with_bloom_filters(&mut self, row_group: RowGroupIter<'_, Error>) -> Result<()> {
+        let copied = self.bloom.shared.lock().unwrap().copied.clone();
+        *copied.lock().unwrap() = Some(vec![]);
+        let columns = Arc::new(Mutex::new(vec![]));
+        let row_group = bloom_filter::track(row_group, copied.clone(), columns.clone());
+        let result = self.writer.write(row_group);
+        let bytes = copied.lock().unwrap().take().unwrap();
+        result?;
+
+        let columns = columns.lock().unwrap();
+        let result =
+            self.bloom
+                .filters
+                .push_pages(&bytes, &columns, self.writer.schema(), &self.schema);
+        if result.is_err() {
+            self.bloom.filters.push_empty();
+        }
+        result
+    }
+
+    // parquet2 does not support bloom filters: its footer is captured and replaced by one
+    // with the offsets of the bloom filters, which are written after the page indexes.
+    fn end_with_bloom_filters(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<u64> {
+        self.bloom.shared.lock().unwrap().captured = Some(vec![]);
+        let size = self.writer.end(key_value_metadata);
+        let mut shared = self.bloom.shared.lock().unwrap();
+        let captured = shared.captured.take().unwrap();
+        let size = size?;
+
+        let (bytes, metadata, size) = self.bloom.filters.replace_footer(&captured, size)?;
+        shared.writer.write_all(&bytes)?;
+        shared.writer.flush()?;
+
+        self.bloom.metadata = Some(metadata);
+        Ok(size)
     }
 }
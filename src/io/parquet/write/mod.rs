@@ -13,12 +13,16 @@
 //! The use of these arrow types will result in no logical type being stored within a parquet file.
 
 mod binary;
+#[cfg(feature = "io_parquet_bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
 mod fixed_len_bytes;
 mod nested;
 mod pages;
+#[cfg(feature = "io_parquet_parallel")]
+mod parallel;
 mod primitive;
 mod row_group;
 mod schema;
@@ -30,6 +34,7 @@ mod utils;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
+use crate::io::parquet::union::to_struct_type;
 use crate::types::days_ms;
 use crate::types::i256;
 use crate::types::NativeType;
@@ -63,10 +68,54 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_pagesize_limit: Option<usize>,
+    /// The maximum size of the dictionary page of a column that is dictionary-encoded on the
+    /// fly, defaults to 1024 * 1024 if None
+    pub dictionary_pagesize_limit: Option<usize>,
+    /// The encoding of a column whose dictionary page would exceed `dictionary_pagesize_limit`.
+    /// [`Encoding::Plain`] is used for columns that cannot be encoded by it. Dictionary
+    /// encodings are not valid fallbacks.
+    pub dictionary_fallback: Encoding,
+    /// The maximum number of rows of a data page, unbounded if None
+    pub max_rows_per_page: Option<usize>,
+    /// The maximum number of rows of a row group, unbounded if None and invalid if 0.
+    /// [`RowGroupIterator`] splits and merges chunks into row groups of this size when this
+    /// or `max_bytes_per_row_group` is set, and writes each chunk as a row group otherwise.
+    /// `FileSink` does not support it.
+    pub max_rows_per_row_group: Option<usize>,
+    /// The maximum estimated size in bytes of a row group (see [`estimated_bytes_size`]),
+    /// unbounded if None and invalid if 0. A row group has at least one row.
+    /// `FileSink` does not support it.
+    pub max_bytes_per_row_group: Option<usize>,
+}
+
+impl Default for WriteOptions {
+    /// Statistics, [`Version::V2`], no compression and no limits other than the default sizes
+    /// of pages.
+    fn default() -> Self {
+        Self {
+            write_statistics: true,
+            version: Version::V2,
+            compression: CompressionOptions::Uncompressed,
+            data_pagesize_limit: None,
+            dictionary_pagesize_limit: None,
+            dictionary_fallback: Encoding::Plain,
+            max_rows_per_page: None,
+            max_rows_per_row_group: None,
+            max_bytes_per_row_group: None,
+        }
+    }
 }
 
 use crate::compute::aggregate::estimated_bytes_size;
+#[cfg(feature = "io_parquet_bloom_filter")]
+pub(crate) use bloom_filter::hash_array;
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+pub use bloom_filter::BloomFilterOptions;
 pub use file::FileWriter;
+#[cfg(feature = "io_parquet_parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_parallel")))]
+pub use parallel::{par_row_group_iter, ParallelRowGroupIterator};
 pub use row_group::{row_group_iter, RowGroupIterator};
 pub use schema::to_parquet_type;
 #[cfg(feature = "io_parquet_async")]
@@ -135,11 +184,38 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
         (encoding, data_type.to_logical_type()),
         (Encoding::Plain, _)
             | (
-                Encoding::DeltaLengthByteArray,
+                Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
-            | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
-            | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
+            | (
+                Encoding::ByteStreamSplit,
+                DataType::Float32 | DataType::Float64
+            )
+            | (
+                Encoding::RleDictionary | Encoding::PlainDictionary,
+                DataType::Dictionary(_, _, _)
+                    | DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Date32
+                    | DataType::Time32(_)
+                    | DataType::Int64
+                    | DataType::Date64
+                    | DataType::Time64(_)
+                    | DataType::Timestamp(_, _)
+                    | DataType::Duration(_)
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::Utf8
+                    | DataType::LargeUtf8
+                    | DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::FixedSizeBinary(_)
+            )
             | (
                 Encoding::DeltaBinaryPacked,
                 DataType::Null
@@ -218,7 +294,55 @@ pub fn get_max_length(nested: &[Nested]) -> usize {
     length
 }
 
+/// Checks that `options` are valid
+pub(crate) fn check_options(options: &WriteOptions) -> Result<()> {
+    if matches!(
+        options.dictionary_fallback,
+        Encoding::RleDictionary | Encoding::PlainDictionary
+    ) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The dictionary fallback encoding cannot be {:?}",
+            options.dictionary_fallback
+        )));
+    }
+    if options.max_rows_per_row_group == Some(0) || options.max_bytes_per_row_group == Some(0) {
+        return Err(Error::InvalidArgumentError(
+            "The maximum rows and bytes of a row group must be larger than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+const DEFAULT_PAGE_SIZE: usize = 1024 * 1024;
+const MAX_PAGE_SIZE: usize = 2usize.pow(31) - 2usize.pow(25); // allowed maximum page size
+
+/// Returns the number of rows of the data pages of a column of `number_of_rows` rows and an
+/// estimated size of `byte_size`.
+pub(crate) fn rows_per_page(
+    byte_size: usize,
+    number_of_rows: usize,
+    options: WriteOptions,
+) -> usize {
+    let max_page_size = options.data_pagesize_limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    let max_page_size = max_page_size.min(MAX_PAGE_SIZE);
+    let bytes_per_row = if number_of_rows == 0 {
+        0
+    } else {
+        ((byte_size as f64) / (number_of_rows as f64)) as usize
+    };
+    let rows_per_page = max_page_size / (bytes_per_row + 1);
+    options
+        .max_rows_per_page
+        .map_or(rows_per_page, |max| rows_per_page.min(max))
+        .max(1)
+}
+
 /// Returns an iterator of [`Page`].
+///
+/// Columns that are not dictionary arrays are dictionary-encoded when `encoding` is
+/// [`Encoding::RleDictionary`] or [`Encoding::PlainDictionary`], unless their dictionary page
+/// would exceed [`WriteOptions::dictionary_pagesize_limit`], in which case they are encoded
+/// by [`WriteOptions::dictionary_fallback`].
 pub fn array_to_pages(
     primitive_array: &dyn Array,
     type_: ParquetPrimitiveType,
@@ -238,6 +362,29 @@ pub fn array_to_pages(
         });
     };
 
+    let data_type = primitive_array.data_type();
+    let encoding = if matches!(
+        encoding,
+        Encoding::RleDictionary | Encoding::PlainDictionary
+    ) && can_encode(data_type, encoding)
+    {
+        check_options(&options)?;
+        let max_dictionary_size = options
+            .dictionary_pagesize_limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .min(MAX_PAGE_SIZE);
+        if let Some(array) = dictionary::encode(primitive_array, max_dictionary_size) {
+            return dictionary::array_to_pages::<u32>(&array, type_, nested, options, encoding);
+        }
+        if can_encode(data_type, options.dictionary_fallback) {
+            options.dictionary_fallback
+        } else {
+            Encoding::Plain
+        }
+    } else {
+        encoding
+    };
+
     let nested = nested.to_vec();
     let primitive_array = primitive_array.to_boxed();
 
@@ -247,15 +394,7 @@ pub fn array_to_pages(
     // primitive after sliced for parquet
     let byte_size = estimated_bytes_size(primitive_array.as_ref());
 
-    const DEFAULT_PAGE_SIZE: usize = 1024 * 1024;
-    let max_page_size = options.data_pagesize_limit.unwrap_or(DEFAULT_PAGE_SIZE);
-    let max_page_size = max_page_size.min(2usize.pow(31) - 2usize.pow(25)); // allowed maximum page size
-    let bytes_per_row = if number_of_rows == 0 {
-        0
-    } else {
-        ((byte_size as f64) / (number_of_rows as f64)) as usize
-    };
-    let rows_per_page = (max_page_size / (bytes_per_row + 1)).max(1);
+    let rows_per_page = rows_per_page(byte_size, number_of_rows, options);
 
     let pages = (0..number_of_rows)
         .step_by(rows_per_page)
@@ -370,15 +509,17 @@ pub fn array_to_page_simple(
             type_,
             encoding,
         ),
-        DataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
+        DataType::Float32 => primitive::array_to_page_float::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             type_,
+            encoding,
         ),
-        DataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
+        DataType::Float64 => primitive::array_to_page_float::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             type_,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -612,13 +753,13 @@ fn array_to_page_nested(
     type_: ParquetPrimitiveType,
     nested: &[Nested],
     options: WriteOptions,
-    _encoding: Encoding,
+    encoding: Encoding,
 ) -> Result<Page> {
     use DataType::*;
     match array.data_type().to_logical_type() {
         Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::nested_array_to_page::<i32, i32>(&array, options, type_, nested)
+            primitive::nested_array_to_page::<i32, i32>(&array, options, type_, nested, encoding)
         }
         Boolean => {
             let array = array.as_any().downcast_ref().unwrap();
@@ -626,59 +767,59 @@ fn array_to_page_nested(
         }
         Utf8 => {
             let array = array.as_any().downcast_ref().unwrap();
-            utf8::nested_array_to_page::<i32>(array, options, type_, nested)
+            utf8::nested_array_to_page::<i32>(array, options, type_, nested, encoding)
         }
         LargeUtf8 => {
             let array = array.as_any().downcast_ref().unwrap();
-            utf8::nested_array_to_page::<i64>(array, options, type_, nested)
+            utf8::nested_array_to_page::<i64>(array, options, type_, nested, encoding)
         }
         Binary => {
             let array = array.as_any().downcast_ref().unwrap();
-            binary::nested_array_to_page::<i32>(array, options, type_, nested)
+            binary::nested_array_to_page::<i32>(array, options, type_, nested, encoding)
         }
         LargeBinary => {
             let array = array.as_any().downcast_ref().unwrap();
-            binary::nested_array_to_page::<i64>(array, options, type_, nested)
+            binary::nested_array_to_page::<i64>(array, options, type_, nested, encoding)
         }
         UInt8 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<u8, i32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<u8, i32>(array, options, type_, nested, encoding)
         }
         UInt16 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<u16, i32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<u16, i32>(array, options, type_, nested, encoding)
         }
         UInt32 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<u32, i32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<u32, i32>(array, options, type_, nested, encoding)
         }
         UInt64 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<u64, i64>(array, options, type_, nested)
+            primitive::nested_array_to_page::<u64, i64>(array, options, type_, nested, encoding)
         }
         Int8 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<i8, i32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<i8, i32>(array, options, type_, nested, encoding)
         }
         Int16 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<i16, i32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<i16, i32>(array, options, type_, nested, encoding)
         }
         Int32 | Date32 | Time32(_) => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<i32, i32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<i32, i32>(array, options, type_, nested, encoding)
         }
         Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested)
+            primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested, encoding)
         }
         Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
+            primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested, encoding)
         }
         Float64 => {
             let array = array.as_any().downcast_ref().unwrap();
-            primitive::nested_array_to_page::<f64, f64>(array, options, type_, nested)
+            primitive::nested_array_to_page::<f64, f64>(array, options, type_, nested, encoding)
         }
         Decimal(precision, _) => {
             let type_ = type_;
@@ -697,7 +838,9 @@ fn array_to_page_nested(
 
                 let array =
                     PrimitiveArray::<i32>::new(DataType::Int32, values, array.validity().cloned());
-                primitive::nested_array_to_page::<i32, i32>(&array, options, type_, nested)
+                primitive::nested_array_to_page::<i32, i32>(
+                    &array, options, type_, nested, encoding,
+                )
             } else if precision <= 18 {
                 let values = array
                     .values()
@@ -708,7 +851,9 @@ fn array_to_page_nested(
 
                 let array =
                     PrimitiveArray::<i64>::new(DataType::Int64, values, array.validity().cloned());
-                primitive::nested_array_to_page::<i64, i64>(&array, options, type_, nested)
+                primitive::nested_array_to_page::<i64, i64>(
+                    &array, options, type_, nested, encoding,
+                )
             } else {
                 let size = decimal_length_from_precision(precision);
 
@@ -750,7 +895,9 @@ fn array_to_page_nested(
 
                 let array =
                     PrimitiveArray::<i32>::new(DataType::Int32, values, array.validity().cloned());
-                primitive::nested_array_to_page::<i32, i32>(&array, options, type_, nested)
+                primitive::nested_array_to_page::<i32, i32>(
+                    &array, options, type_, nested, encoding,
+                )
             } else if precision <= 18 {
                 let values = array
                     .values()
@@ -761,7 +908,9 @@ fn array_to_page_nested(
 
                 let array =
                     PrimitiveArray::<i64>::new(DataType::Int64, values, array.validity().cloned());
-                primitive::nested_array_to_page::<i64, i64>(&array, options, type_, nested)
+                primitive::nested_array_to_page::<i64, i64>(
+                    &array, options, type_, nested, encoding,
+                )
             } else if precision <= 38 {
                 let size = decimal_length_from_precision(precision);
                 let statistics = if options.write_statistics {
@@ -863,7 +1012,10 @@ fn transverse_recursive<T, F: Fn(&DataType) -> T + Clone>(
                 unreachable!()
             }
         }
-        Union => todo!(),
+        Union => {
+            let data_type = to_struct_type(data_type.to_logical_type());
+            transverse_recursive(&data_type, map, encodings)
+        }
     }
 }
 
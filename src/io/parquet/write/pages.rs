@@ -6,6 +6,7 @@ use crate::array::{ListArray, MapArray, StructArray};
 use crate::bitmap::Bitmap;
 use crate::datatypes::PhysicalType;
 use crate::io::parquet::read::schema::is_nullable;
+use crate::io::parquet::union::to_struct;
 use crate::offset::{Offset, OffsetsBuffer};
 use crate::{
     array::Array,
@@ -236,6 +237,7 @@ pub fn array_to_columns<A: AsRef<dyn Array> + Send + Sync>(
     options: WriteOptions,
     encoding: &[Encoding],
 ) -> Result<Vec<DynIter<'static, Result<Page>>>> {
+    let array = to_struct(array.as_ref())?;
     let array = array.as_ref();
     let nested = to_nested(array, &type_)?;
 
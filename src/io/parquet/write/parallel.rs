@@ -0,0 +1,205 @@
+//! Encoding of the column chunks of row groups in parallel.
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, Receiver};
+
+use parquet2::schema::types::ParquetType;
+use rayon::prelude::*;
+
+use crate::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema,
+    error::{Error, Result},
+};
+
+use super::row_group::RowGroupChunks;
+use super::{
+    array_to_columns, check_options, compress, to_parquet_schema, CompressedPage, DynIter,
+    DynStreamingIterator, Encoding, FallibleStreamingIterator, RowGroupIter, SchemaDescriptor,
+    WriteOptions,
+};
+
+/// The compressed pages of each column chunk of a row group
+type Columns = Vec<Vec<CompressedPage>>;
+
+fn encode<A: AsRef<dyn Array> + Send + Sync>(
+    chunk: &Chunk<A>,
+    encodings: &[Vec<Encoding>],
+    fields: &[ParquetType],
+    options: WriteOptions,
+) -> Result<Columns> {
+    if encodings.len() != chunk.arrays().len() || fields.len() != chunk.arrays().len() {
+        return Err(Error::InvalidArgumentError(
+            "The number of arrays in the chunk must equal the number of fields and of encodings"
+                .to_string(),
+        ));
+    }
+    let columns = chunk
+        .arrays()
+        .par_iter()
+        .zip(fields.par_iter())
+        .zip(encodings.par_iter())
+        .map(|((array, type_), encoding)| {
+            array_to_columns(array.as_ref(), type_.clone(), options, encoding)?
+                .into_par_iter()
+                .map(|pages| {
+                    pages
+                        .map(|page| Ok(compress(page?, vec![], options.compression)?))
+                        .collect::<Result<Vec<_>>>()
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // the order of the column chunks is the order of the leaves, as when encoded sequentially
+    Ok(columns.into_iter().flatten().collect())
+}
+
+/// A [`FallibleStreamingIterator`] over the compressed pages of a column chunk
+struct Pages {
+    pages: std::vec::IntoIter<CompressedPage>,
+    current: Option<CompressedPage>,
+}
+
+impl FallibleStreamingIterator for Pages {
+    type Item = CompressedPage;
+    type Error = Error;
+
+    fn advance(&mut self) -> Result<()> {
+        self.current = self.pages.next();
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
+
+fn to_row_group(columns: Columns) -> RowGroupIter<'static, Error> {
+    DynIter::new(columns.into_iter().map(|pages| {
+        Ok(DynStreamingIterator::new(Pages {
+            pages: pages.into_iter(),
+            current: None,
+        }))
+    }))
+}
+
+/// Like [`row_group_iter`](super::row_group_iter), but encodes and compresses the column
+/// chunks of `chunk` in parallel, in rayon's global thread pool, before returning.
+///
+/// The pages are identical to those encoded sequentially.
+/// # Errors
+/// Iff the number of arrays, `encodings` and `fields` differ or encoding fails.
+pub fn par_row_group_iter<A: AsRef<dyn Array> + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Vec<Encoding>>,
+    fields: Vec<ParquetType>,
+    options: WriteOptions,
+) -> Result<RowGroupIter<'static, Error>> {
+    encode(&chunk, &encodings, &fields, options).map(to_row_group)
+}
+
+/// Like [`RowGroupIterator`](super::RowGroupIterator) (including the sizing of row groups),
+/// but encodes the column chunks of each row group in parallel with [`par_row_group_iter`].
+///
+/// While a row group is returned (and e.g. written by [`FileWriter`](super::FileWriter)),
+/// the next row group is encoded in rayon's global thread pool.
+pub struct ParallelRowGroupIterator<
+    A: AsRef<dyn Array> + Send + Sync + 'static,
+    I: Iterator<Item = Result<Chunk<A>>>,
+> {
+    iter: RowGroupChunks<A, I>,
+    options: WriteOptions,
+    parquet_schema: SchemaDescriptor,
+    encodings: Vec<Vec<Encoding>>,
+    /// The row group being encoded
+    next: Option<Receiver<Result<Columns>>>,
+}
+
+impl<A: AsRef<dyn Array> + Send + Sync + 'static, I: Iterator<Item = Result<Chunk<A>>>>
+    ParallelRowGroupIterator<A, I>
+{
+    /// Creates a new [`ParallelRowGroupIterator`] from an iterator over [`Chunk`].
+    ///
+    /// # Errors
+    /// Iff
+    /// * the Arrow schema can't be converted to a valid Parquet schema.
+    /// * the length of the encodings is different from the number of fields in schema
+    /// * `options.dictionary_fallback` is a dictionary encoding
+    /// * `options.max_rows_per_row_group` or `options.max_bytes_per_row_group` is 0
+    pub fn try_new(
+        iter: I,
+        schema: &Schema,
+        options: WriteOptions,
+        encodings: Vec<Vec<Encoding>>,
+    ) -> Result<Self> {
+        if encodings.len() != schema.fields.len() {
+            return Err(Error::InvalidArgumentError(
+                "The number of encodings must equal the number of fields".to_string(),
+            ));
+        }
+        check_options(&options)?;
+        let parquet_schema = to_parquet_schema(schema)?;
+
+        Ok(Self {
+            iter: RowGroupChunks::new(iter, &options),
+            options,
+            parquet_schema,
+            encodings,
+            next: None,
+        })
+    }
+
+    /// Returns the [`SchemaDescriptor`] of the [`ParallelRowGroupIterator`].
+    pub fn parquet_schema(&self) -> &SchemaDescriptor {
+        &self.parquet_schema
+    }
+
+    // starts encoding the next chunk of `iter`, if any
+    fn spawn(&mut self) -> Option<Receiver<Result<Columns>>> {
+        let chunk = self.iter.next()?;
+        let (sender, receiver) = channel();
+        match chunk {
+            Ok(chunk) => {
+                let encodings = self.encodings.clone();
+                let fields = self.parquet_schema.fields().to_vec();
+                let options = self.options;
+                rayon::spawn(move || {
+                    // a panic in rayon's threads aborts, so it is returned as an error
+                    let columns = catch_unwind(AssertUnwindSafe(|| {
+                        encode(&chunk, &encodings, &fields, options)
+                    }))
+                    .unwrap_or_else(|payload| {
+                        let message = payload
+                            .downcast_ref::<&str>()
+                            .map(|x| x.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+                        Err(Error::External(
+                            "The encoding of a row group panicked".to_string(),
+                            message.into(),
+                        ))
+                    });
+                    // the receiver is dropped when the iterator is
+                    let _ = sender.send(columns);
+                });
+            }
+            Err(error) => sender.send(Err(error)).unwrap(),
+        }
+        Some(receiver)
+    }
+}
+
+impl<A: AsRef<dyn Array> + Send + Sync + 'static, I: Iterator<Item = Result<Chunk<A>>>> Iterator
+    for ParallelRowGroupIterator<A, I>
+{
+    type Item = Result<RowGroupIter<'static, Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take().or_else(|| self.spawn())?;
+        self.next = self.spawn();
+
+        // the sender always sends the result of the encoding
+        let columns = current.recv().unwrap();
+        Some(columns.map(to_row_group))
+    }
+}
@@ -78,6 +78,34 @@ where
     buffer
 }
 
+/// Encodes the values of `array` as `BYTE_STREAM_SPLIT`: byte `k` of the `i`-th value is
+/// written at position `k * num_values + i`.
+pub(crate) fn encode_byte_stream_split<T, P>(
+    array: &PrimitiveArray<T>,
+    is_optional: bool,
+    mut buffer: Vec<u8>,
+) -> Vec<u8>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    let plain = encode_plain::<T, P>(array, is_optional, vec![]);
+
+    let size = std::mem::size_of::<P>();
+    let num_values = plain.len() / size;
+    let start = buffer.len();
+    buffer.resize(start + plain.len(), 0);
+    let streams = &mut buffer[start..];
+    plain.chunks_exact(size).enumerate().for_each(|(i, value)| {
+        value
+            .iter()
+            .enumerate()
+            .for_each(|(k, byte)| streams[k * num_values + i] = *byte)
+    });
+    buffer
+}
+
 pub fn array_to_page_plain<T, P>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -110,6 +138,26 @@ where
     }
 }
 
+pub fn array_to_page_float<T, P>(
+    array: &PrimitiveArray<T>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+    encoding: Encoding,
+) -> Result<DataPage, Error>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    match encoding {
+        Encoding::ByteStreamSplit => {
+            array_to_page(array, options, type_, encoding, encode_byte_stream_split)
+        }
+        Encoding::Plain => array_to_page(array, options, type_, encoding, encode_plain),
+        other => Err(Error::nyi(format!("Encoding float as {other:?}"))),
+    }
+}
+
 pub fn array_to_page<T, P, F: Fn(&PrimitiveArray<T>, bool, Vec<u8>) -> Vec<u8>>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -1,6 +1,7 @@
 mod basic;
 mod nested;
 
+pub use basic::array_to_page_float;
 pub use basic::array_to_page_integer;
 pub use basic::array_to_page_plain;
 pub(crate) use basic::build_statistics;
@@ -5,12 +5,13 @@ use parquet2::{encoding::Encoding, page::DataPage, types::NativeType};
 use super::super::nested;
 use super::super::utils;
 use super::super::WriteOptions;
-use super::basic::{build_statistics, encode_plain};
+use super::basic::{build_statistics, encode_byte_stream_split, encode_plain};
 use crate::io::parquet::read::schema::is_nullable;
 use crate::io::parquet::write::Nested;
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    datatypes::DataType,
+    error::{Error, Result},
     types::NativeType as ArrowNativeType,
 };
 
@@ -19,12 +20,25 @@ pub fn array_to_page<T, R>(
     options: WriteOptions,
     type_: PrimitiveType,
     nested: &[Nested],
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
     R: NativeType,
     T: num_traits::AsPrimitive<R>,
 {
+    if encoding == Encoding::ByteStreamSplit
+        && !matches!(
+            array.data_type().to_logical_type(),
+            DataType::Float32 | DataType::Float64
+        )
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "ByteStreamSplit can only encode floats, not {:?}",
+            array.data_type()
+        )));
+    }
+
     let is_optional = is_nullable(&type_.field_info);
 
     let mut buffer = vec![];
@@ -32,7 +46,14 @@ where
     let (repetition_levels_byte_length, definition_levels_byte_length) =
         nested::write_rep_and_def(options.version, nested, &mut buffer)?;
 
-    let buffer = encode_plain(array, is_optional, buffer);
+    // encodings other than `ByteStreamSplit` are not yet supported for nested types
+    let (buffer, encoding) = match encoding {
+        Encoding::ByteStreamSplit => (
+            encode_byte_stream_split(array, is_optional, buffer),
+            encoding,
+        ),
+        _ => (encode_plain(array, is_optional, buffer), Encoding::Plain),
+    };
 
     let statistics = if options.write_statistics {
         Some(serialize_statistics(&build_statistics(
@@ -53,6 +74,6 @@ where
         statistics,
         type_,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
@@ -4,15 +4,16 @@ use parquet2::write::Compressor;
 use parquet2::FallibleStreamingIterator;
 
 use crate::{
-    array::Array,
+    array::{growable::make_growable, Array},
     chunk::Chunk,
+    compute::aggregate::estimated_bytes_size,
     datatypes::Schema,
     error::{Error, Result},
 };
 
 use super::{
-    array_to_columns, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_columns, check_options, to_parquet_schema, DynIter, DynStreamingIterator, Encoding,
+    RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 
 /// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
@@ -57,11 +58,160 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     )
 }
 
+fn to_boxed<A: AsRef<dyn Array>>(chunk: Chunk<A>) -> Chunk<Box<dyn Array>> {
+    Chunk::new(
+        chunk
+            .into_arrays()
+            .into_iter()
+            .map(|x| x.as_ref().to_boxed())
+            .collect(),
+    )
+}
+
+/// Concatenates `chunks` into a single [`Chunk`].
+fn concatenate(mut chunks: Vec<Chunk<Box<dyn Array>>>) -> Result<Chunk<Box<dyn Array>>> {
+    if chunks.len() == 1 {
+        return Ok(chunks.pop().unwrap());
+    }
+    let num_columns = chunks[0].arrays().len();
+    let length = chunks.iter().map(|x| x.len()).sum();
+    if chunks.iter().any(|x| x.arrays().len() != num_columns) {
+        return Err(Error::InvalidArgumentError(
+            "The chunks of a row group must have the same number of arrays".to_string(),
+        ));
+    }
+    let arrays = (0..num_columns)
+        .map(|column| {
+            let arrays = chunks
+                .iter()
+                .map(|x| x.arrays()[column].as_ref())
+                .collect::<Vec<_>>();
+            if arrays
+                .iter()
+                .any(|x| x.data_type() != arrays[0].data_type())
+            {
+                return Err(Error::InvalidArgumentError(
+                    "The arrays of a column of a row group must have the same data type"
+                        .to_string(),
+                ));
+            }
+            let use_validity = arrays.iter().any(|x| x.null_count() > 0);
+            let mut growable = make_growable(&arrays, use_validity, length);
+            arrays
+                .iter()
+                .enumerate()
+                .for_each(|(index, array)| growable.extend(index, 0, array.len()));
+            Ok(growable.as_box())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+/// An iterator adapter that splits and merges the [`Chunk`]s of an iterator into chunks of
+/// [`WriteOptions::max_rows_per_row_group`] rows and
+/// [`WriteOptions::max_bytes_per_row_group`] estimated bytes, the last one possibly smaller.
+/// Chunks are returned as they are when neither is set.
+pub(super) struct RowGroupChunks<A: AsRef<dyn Array>, I: Iterator<Item = Result<Chunk<A>>>> {
+    iter: I,
+    max_rows: Option<usize>,
+    max_bytes: Option<usize>,
+    /// The rows of the last chunk of `iter` not yet returned, and their bytes per row
+    remainder: Option<(Chunk<Box<dyn Array>>, f64)>,
+    /// The chunks of the next row group, with their number of rows and estimated bytes
+    buffer: Vec<Chunk<Box<dyn Array>>>,
+    rows: usize,
+    bytes: usize,
+}
+
+impl<A: AsRef<dyn Array>, I: Iterator<Item = Result<Chunk<A>>>> RowGroupChunks<A, I> {
+    pub(super) fn new(iter: I, options: &WriteOptions) -> Self {
+        Self {
+            iter,
+            max_rows: options.max_rows_per_row_group,
+            max_bytes: options.max_bytes_per_row_group,
+            remainder: None,
+            buffer: vec![],
+            rows: 0,
+            bytes: 0,
+        }
+    }
+
+    fn flush(&mut self) -> Result<Chunk<Box<dyn Array>>> {
+        self.rows = 0;
+        self.bytes = 0;
+        concatenate(std::mem::take(&mut self.buffer))
+    }
+}
+
+impl<A: AsRef<dyn Array>, I: Iterator<Item = Result<Chunk<A>>>> Iterator for RowGroupChunks<A, I> {
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.max_rows.is_none() && self.max_bytes.is_none() {
+            return self.iter.next().map(|x| x.map(to_boxed));
+        }
+        loop {
+            let (chunk, bytes_per_row) = match self.remainder.take() {
+                Some(remainder) => remainder,
+                None => match self.iter.next() {
+                    Some(Ok(chunk)) => {
+                        let chunk = to_boxed(chunk);
+                        let bytes = chunk
+                            .arrays()
+                            .iter()
+                            .map(|x| estimated_bytes_size(x.as_ref()))
+                            .sum::<usize>();
+                        let bytes_per_row = bytes as f64 / chunk.len().max(1) as f64;
+                        (chunk, bytes_per_row)
+                    }
+                    Some(Err(error)) => return Some(Err(error)),
+                    None => break,
+                },
+            };
+            if chunk.is_empty() {
+                continue;
+            }
+
+            // the row group is not full, so at least one row fits in it
+            let rows = self.max_rows.map_or(usize::MAX, |max| max - self.rows);
+            let rows_by_bytes = self.max_bytes.map_or(usize::MAX, |max| {
+                ((max - self.bytes) as f64 / bytes_per_row).ceil() as usize
+            });
+            let length = rows.min(rows_by_bytes).min(chunk.len());
+            let chunk = if length < chunk.len() {
+                let (head, tail) = chunk
+                    .into_arrays()
+                    .into_iter()
+                    .map(|x| (x.sliced(0, length), x.sliced(length, x.len() - length)))
+                    .unzip();
+                self.remainder = Some((Chunk::new(tail), bytes_per_row));
+                Chunk::new(head)
+            } else {
+                chunk
+            };
+            self.rows += length;
+            self.bytes += (length as f64 * bytes_per_row) as usize;
+            self.buffer.push(chunk);
+
+            let is_full = self.max_rows.map_or(false, |max| self.rows >= max)
+                || self.max_bytes.map_or(false, |max| self.bytes >= max);
+            if is_full || self.remainder.is_some() {
+                return Some(self.flush());
+            }
+        }
+        (!self.buffer.is_empty()).then(|| self.flush())
+    }
+}
+
 /// An iterator adapter that converts an iterator over [`Chunk`] into an iterator
 /// of row groups.
+///
+/// Each chunk is a row group, unless [`WriteOptions::max_rows_per_row_group`] or
+/// [`WriteOptions::max_bytes_per_row_group`] are set, in which case the chunks are split and
+/// merged into row groups of those sizes.
 /// Use it to create an iterator consumable by the parquet's API.
 pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> {
-    iter: I,
+    iter: RowGroupChunks<A, I>,
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Vec<Encoding>>,
@@ -74,6 +224,8 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
     /// Iff
     /// * the Arrow schema can't be converted to a valid Parquet schema.
     /// * the length of the encodings is different from the number of fields in schema
+    /// * `options.dictionary_fallback` is a dictionary encoding
+    /// * `options.max_rows_per_row_group` or `options.max_bytes_per_row_group` is 0
     pub fn try_new(
         iter: I,
         schema: &Schema,
@@ -85,10 +237,11 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
                 "The number of encodings must equal the number of fields".to_string(),
             ));
         }
+        check_options(&options)?;
         let parquet_schema = to_parquet_schema(schema)?;
 
         Ok(Self {
-            iter,
+            iter: RowGroupChunks::new(iter, &options),
             options,
             parquet_schema,
             encodings,
@@ -18,7 +18,7 @@ use crate::{
     io::parquet::write::decimal_length_from_precision,
 };
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{union::to_struct_type, ARROW_SCHEMA_META_KEY};
 
 pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
     let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields));
@@ -261,6 +261,15 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                 name, repetition, None, None, fields, None,
             ))
         }
+        DataType::Union(..) => {
+            // unions are written as structs of their (nullable) variants and type ids
+            let field = Field::new(
+                name,
+                to_struct_type(field.data_type.to_logical_type()),
+                field.is_nullable,
+            );
+            to_parquet_type(&field)
+        }
         DataType::Dictionary(_, value, _) => {
             let dict_field = Field::new(name.as_str(), value.as_ref().clone(), field.is_nullable);
             to_parquet_type(&dict_field)
@@ -1,4 +1,6 @@
 use ahash::AHashMap;
+#[cfg(feature = "io_parquet_bloom_filter")]
+use std::sync::{Arc, Mutex};
 use std::{pin::Pin, task::Poll};
 
 use futures::{future::BoxFuture, AsyncWrite, AsyncWriteExt, FutureExt, Sink, TryFutureExt};
@@ -8,9 +10,16 @@ use parquet2::write::WriteOptions as ParquetWriteOptions;
 
 use crate::{array::Array, chunk::Chunk, datatypes::Schema, error::Error};
 
+#[cfg(feature = "io_parquet_bloom_filter")]
+use super::bloom_filter::{BloomFilterOptions, BloomFilters, Shared, SharedWriter};
 use super::file::add_arrow_schema;
 use super::{Encoding, SchemaDescriptor, WriteOptions};
 
+#[cfg(not(feature = "io_parquet_bloom_filter"))]
+type Streamer<W> = FileStreamer<W>;
+#[cfg(feature = "io_parquet_bloom_filter")]
+type Streamer<W> = FileStreamer<SharedWriter<W>>;
+
 /// Sink that writes array [`chunks`](Chunk) as a Parquet file.
 ///
 /// Any values in the sink's `metadata` field will be written to the file's footer
@@ -36,6 +45,7 @@ use super::{Encoding, SchemaDescriptor, WriteOptions};
 ///     compression: CompressionOptions::Uncompressed,
 ///     version: Version::V2,
 ///     data_pagesize_limit: None,
+///     ..Default::default()
 /// };
 ///
 /// let mut buffer = vec![];
@@ -57,12 +67,16 @@ use super::{Encoding, SchemaDescriptor, WriteOptions};
 /// # }).unwrap();
 /// ```
 pub struct FileSink<'a, W: AsyncWrite + Send + Unpin> {
-    writer: Option<FileStreamer<W>>,
-    task: Option<BoxFuture<'a, Result<Option<FileStreamer<W>>, Error>>>,
+    writer: Option<Streamer<W>>,
+    task: Option<BoxFuture<'a, Result<Option<Streamer<W>>, Error>>>,
     options: WriteOptions,
     encodings: Vec<Vec<Encoding>>,
     schema: Schema,
     parquet_schema: SchemaDescriptor,
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    bloom: BloomFilters,
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    shared: Arc<Mutex<Shared<W>>>,
     /// Key-value metadata that will be written to the file on close.
     pub metadata: AHashMap<String, Option<String>>,
 }
@@ -77,6 +91,9 @@ where
     /// Iff
     /// * the Arrow schema can't be converted to a valid Parquet schema.
     /// * the length of the encodings is different from the number of fields in schema
+    /// * `options.dictionary_fallback` is a dictionary encoding
+    /// * `options.max_rows_per_row_group` or `options.max_bytes_per_row_group` is set, since
+    /// each chunk is written as its own row group
     pub fn try_new(
         writer: W,
         schema: Schema,
@@ -88,9 +105,20 @@ where
                 "The number of encodings must equal the number of fields".to_string(),
             ));
         }
+        crate::io::parquet::write::check_options(&options)?;
+        if options.max_rows_per_row_group.is_some() || options.max_bytes_per_row_group.is_some() {
+            return Err(Error::InvalidArgumentError(
+                "FileSink writes each chunk as a row group and does not support row group limits"
+                    .to_string(),
+            ));
+        }
 
         let parquet_schema = crate::io::parquet::write::to_parquet_schema(&schema)?;
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        let shared = Shared::new(writer);
+        #[cfg(feature = "io_parquet_bloom_filter")]
+        let writer = SharedWriter(shared.clone());
         let writer = FileStreamer::new(
             writer,
             parquet_schema.clone(),
@@ -107,10 +135,30 @@ where
             schema,
             encodings,
             parquet_schema,
+            #[cfg(feature = "io_parquet_bloom_filter")]
+            bloom: BloomFilters::default(),
+            #[cfg(feature = "io_parquet_bloom_filter")]
+            shared,
             metadata: AHashMap::default(),
         })
     }
 
+    /// Sets the options of the bloom filter of each parquet column of the file (in the order
+    /// of [`Self::parquet_schema`]'s columns), where `None` writes no bloom filter. They apply
+    /// to the chunks sent afterwards.
+    /// # Errors
+    /// Errors iff the number of options differs from the number of parquet columns, a false
+    /// positive probability is not in `(0, 1)` or a bloom filter was requested for a column of
+    /// an unsupported type.
+    #[cfg(feature = "io_parquet_bloom_filter")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+    pub fn set_bloom_filters(
+        &mut self,
+        options: Vec<Option<BloomFilterOptions>>,
+    ) -> Result<(), Error> {
+        self.bloom.set(&self.schema, options)
+    }
+
     /// The Arrow [`Schema`] for the file.
     pub fn schema(&self) -> &Schema {
         &self.schema
@@ -163,6 +211,11 @@ where
         }
         let this = self.get_mut();
         if let Some(mut writer) = this.writer.take() {
+            #[cfg(feature = "io_parquet_bloom_filter")]
+            if let Err(error) = this.bloom.push_chunk(&item) {
+                this.writer = Some(writer);
+                return Err(error);
+            }
             let rows = crate::io::parquet::write::row_group_iter(
                 item,
                 this.encodings.clone(),
@@ -217,6 +270,23 @@ where
                     };
                     let kv_meta = add_arrow_schema(&this.schema, metadata);
 
+                    #[cfg(feature = "io_parquet_bloom_filter")]
+                    if this.bloom.has_bitsets() {
+                        let bloom = std::mem::take(&mut this.bloom);
+                        let shared = this.shared.clone();
+                        this.task = Some(Box::pin(async move {
+                            // see `FileWriter::end`
+                            shared.lock().unwrap().captured = Some(vec![]);
+                            let size = writer.end(kv_meta).map_err(Error::from).await;
+                            let captured = shared.lock().unwrap().captured.take().unwrap();
+                            let (bytes, _, _) = bloom.replace_footer(&captured, size?)?;
+                            let mut writer = writer.into_inner();
+                            writer.write_all(&bytes).await?;
+                            writer.close().map_err(Error::from).await?;
+                            Ok(None)
+                        }));
+                        return this.poll_complete(cx);
+                    }
                     this.task = Some(Box::pin(async move {
                         writer.end(kv_meta).map_err(Error::from).await?;
                         writer.into_inner().close().map_err(Error::from).await?;
@@ -5,7 +5,7 @@ use parquet2::{
     statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
 };
 
-use super::super::binary::{encode_delta, ord_binary};
+use super::super::binary::{encode_delta, encode_prefixed, ord_binary};
 use super::super::utils;
 use super::super::WriteOptions;
 use crate::{
@@ -39,6 +39,18 @@ pub(crate) fn encode_plain<O: Offset>(
     }
 }
 
+pub(crate) fn encode_delta_byte_array<O: Offset>(
+    array: &Utf8Array<O>,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) {
+    if is_optional {
+        encode_prefixed(array.iter().flatten().map(|x| x.as_bytes()), buffer)
+    } else {
+        encode_prefixed(array.values_iter().map(|x| x.as_bytes()), buffer)
+    }
+}
+
 pub fn array_to_page<O: Offset>(
     array: &Utf8Array<O>,
     options: WriteOptions,
@@ -68,6 +80,7 @@ pub fn array_to_page<O: Offset>(
             is_optional,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => encode_delta_byte_array(array, is_optional, &mut buffer),
         _ => {
             return Err(Error::InvalidArgumentError(format!(
                 "Datatype {:?} cannot be encoded by {:?} encoding",
@@ -1,7 +1,9 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
-use crate::array::{Array, DictionaryKey, FixedSizeListArray, ListArray, StructArray};
+use crate::array::{
+    Array, DictionaryKey, FixedSizeListArray, ListArray, MapArray, StructArray, UnionArray,
+};
 use crate::datatypes::DataType;
 use crate::error::Error;
 use crate::offset::Offset;
@@ -313,6 +315,59 @@ fn mmap_list<O: Offset, T: AsRef<[u8]>>(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+fn mmap_map<T: AsRef<[u8]>>(
+    data: Arc<T>,
+    node: &Node,
+    block_offset: usize,
+    data_type: &DataType,
+    ipc_field: &IpcField,
+    dictionaries: &Dictionaries,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<ArrowArray, Error> {
+    let child = MapArray::try_get_field(data_type)?.data_type();
+
+    let num_rows: usize = node
+        .length()
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+
+    let null_count: usize = node
+        .null_count()
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+
+    let data_ref = data.as_ref().as_ref();
+
+    let validity = get_validity(data_ref, block_offset, buffers, null_count)?.map(|x| x.as_ptr());
+
+    let offsets = get_buffer::<i32>(data_ref, block_offset, buffers, num_rows + 1)?.as_ptr();
+
+    let field = get_array(
+        data.clone(),
+        block_offset,
+        child,
+        &ipc_field.fields[0],
+        dictionaries,
+        field_nodes,
+        buffers,
+    )?;
+
+    // NOTE: offsets and field invariants are _not_ validated
+    Ok(unsafe {
+        create_array(
+            data,
+            num_rows,
+            null_count,
+            [validity, Some(offsets)].into_iter(),
+            [field].into_iter(),
+            None,
+            None,
+        )
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn mmap_fixed_size_list<T: AsRef<[u8]>>(
     data: Arc<T>,
@@ -422,6 +477,65 @@ fn mmap_struct<T: AsRef<[u8]>>(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+fn mmap_union<T: AsRef<[u8]>>(
+    data: Arc<T>,
+    node: &Node,
+    block_offset: usize,
+    data_type: &DataType,
+    ipc_field: &IpcField,
+    dictionaries: &Dictionaries,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<ArrowArray, Error> {
+    let (fields, _, mode) = UnionArray::try_get_all(data_type)?;
+
+    let num_rows: usize = node
+        .length()
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+
+    let data_ref = data.as_ref().as_ref();
+
+    // unions have no validity buffer since IPC V5, the version written by this crate
+    let types = get_buffer::<i8>(data_ref, block_offset, buffers, num_rows)?.as_ptr();
+    let offsets = if mode.is_sparse() {
+        None
+    } else {
+        Some(get_buffer::<i32>(data_ref, block_offset, buffers, num_rows)?.as_ptr())
+    };
+
+    let values = fields
+        .iter()
+        .map(|f| &f.data_type)
+        .zip(ipc_field.fields.iter())
+        .map(|(child, ipc)| {
+            get_array(
+                data.clone(),
+                block_offset,
+                child,
+                ipc,
+                dictionaries,
+                field_nodes,
+                buffers,
+            )
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    // NOTE: types and offsets invariants are _not_ validated
+    Ok(unsafe {
+        create_array(
+            data,
+            num_rows,
+            0,
+            [Some(types), offsets].into_iter(),
+            values.into_iter(),
+            None,
+            None,
+        )
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn mmap_dict<K: DictionaryKey, T: AsRef<[u8]>>(
     data: Arc<T>,
@@ -542,7 +656,26 @@ fn get_array<T: AsRef<[u8]>>(
                 buffers,
             )
         }),
-        _ => todo!(),
+        Map => mmap_map(
+            data,
+            &node,
+            block_offset,
+            data_type,
+            ipc_field,
+            dictionaries,
+            field_nodes,
+            buffers,
+        ),
+        Union => mmap_union(
+            data,
+            &node,
+            block_offset,
+            data_type,
+            ipc_field,
+            dictionaries,
+            field_nodes,
+            buffers,
+        ),
     }
 }
 
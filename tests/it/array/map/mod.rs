@@ -52,3 +52,73 @@ fn basics() {
         )) as Box<dyn Array>
     );
 }
+
+#[test]
+fn mutable_push() {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend(vec![
+            Some(vec![(Some("a"), Some(1)), (Some("b"), None)]),
+            None,
+            Some(vec![]),
+            Some(vec![(Some("c"), Some(3))]),
+        ])
+        .unwrap();
+    let array: MapArray = array.into();
+
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2, 3]);
+    assert_eq!(array.validity(), Some(&[true, false, true, true].into()));
+
+    let keys = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c")]);
+    let values = Int32Array::from([Some(1), None, Some(3)]);
+    let field = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(field.values()[0].as_ref(), &keys as &dyn Array);
+    assert_eq!(field.values()[1].as_ref(), &values as &dyn Array);
+}
+
+#[test]
+fn mutable_null_keys() {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.mut_keys().push(Some("a"));
+    array.mut_values().push(Some(1));
+    array.try_push_valid().unwrap();
+
+    array.mut_keys().push(None::<&str>);
+    array.mut_values().push(Some(2));
+    assert!(array.try_push_valid().is_err());
+
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    assert!(array.try_push(Some(vec![(None::<&str>, Some(1))])).is_err());
+}
+
+#[test]
+fn mutable_try_new() {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(Box::new(Field::new("entries", entries, false)), false);
+
+    let mut array = MutableMapArray::try_new(
+        data_type.clone(),
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+    )
+    .unwrap();
+    array.mut_keys().push(Some("a"));
+    array.mut_values().push(Some(1));
+    array.try_push_valid().unwrap();
+    array.mut_keys().push(Some("b"));
+    assert!(array.try_push_valid().is_err());
+
+    assert!(MutableMapArray::try_new(
+        data_type,
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i64>::new(),
+    )
+    .is_err());
+}
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use arrow2::array::ord::build_compare;
 use arrow2::array::*;
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, TimeUnit};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +115,16 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn timestamp_tz() -> Result<()> {
+    let tz = |unit| DataType::Timestamp(unit, Some("+01:00".to_string()));
+    let array1 = Int64Array::from_slice([1, 2]).to(tz(TimeUnit::Second));
+    let array2 = Int64Array::from_slice([2000]).to(tz(TimeUnit::Millisecond));
+
+    let cmp = build_compare(&array1, &array1)?;
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+
+    assert!(build_compare(&array1, &array2).is_err());
+    Ok(())
+}
@@ -371,3 +371,96 @@ fn types_must_be_in_bounds() -> Result<()> {
     assert!(UnionArray::try_new(data_type, types, fields.clone(), None).is_err());
     Ok(())
 }
+
+enum Value {
+    Int(i32),
+    Str(&'static str),
+}
+
+impl TaggedValue for Value {
+    fn type_id(&self) -> i8 {
+        match self {
+            Value::Int(_) => 0,
+            Value::Str(_) => 1,
+        }
+    }
+
+    fn try_push_to(self, field: &mut dyn MutableArray) -> Result<()> {
+        let field = field.as_mut_any();
+        match self {
+            Value::Int(v) => field
+                .downcast_mut::<MutablePrimitiveArray<i32>>()
+                .unwrap()
+                .try_push(Some(v)),
+            Value::Str(v) => field
+                .downcast_mut::<MutableUtf8Array<i32>>()
+                .unwrap()
+                .try_push(Some(v)),
+        }
+    }
+}
+
+fn mutable_union(mode: UnionMode) -> MutableUnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    MutableUnionArray::new(
+        DataType::Union(fields, None, mode),
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    )
+}
+
+#[test]
+fn mutable_sparse() -> Result<()> {
+    let mut array = mutable_union(UnionMode::Sparse);
+    array.try_extend(vec![Some(Value::Int(1)), None, Some(Value::Str("c"))])?;
+    let array: UnionArray = array.into();
+
+    assert_eq!(array.types().as_slice(), &[0, 0, 1]);
+    assert!(array.offsets().is_none());
+    assert_eq!(
+        array.fields()[0].as_ref(),
+        &Int32Array::from([Some(1), None, None]) as &dyn Array
+    );
+    assert_eq!(
+        array.fields()[1].as_ref(),
+        &Utf8Array::<i32>::from([None, None, Some("c")]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let mut array = mutable_union(UnionMode::Dense);
+    array.try_extend(vec![
+        Some(Value::Int(1)),
+        Some(Value::Str("b")),
+        None,
+        Some(Value::Str("d")),
+    ])?;
+
+    array
+        .value::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(Some(5));
+    array.try_push_type(0)?;
+    assert!(array.try_push_type(1).is_err());
+    assert!(array.try_push_type(2).is_err());
+
+    let array: UnionArray = array.into();
+    assert_eq!(array.types().as_slice(), &[0, 1, 0, 1, 0]);
+    assert_eq!(array.offsets().unwrap().as_slice(), &[0, 0, 1, 1, 2]);
+    assert_eq!(
+        array.fields()[0].as_ref(),
+        &Int32Array::from([Some(1), None, Some(5)]) as &dyn Array
+    );
+    assert_eq!(
+        array.fields()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["b", "d"]) as &dyn Array
+    );
+    Ok(())
+}
@@ -0,0 +1,280 @@
+#![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal256::*;
+use arrow2::compute::arithmetics::{self, ArrayAdd, ArrayCheckedDiv, ArrayMul, ArraySub};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::i256;
+
+fn to_i256(values: &[Option<i128>]) -> PrimitiveArray<i256> {
+    values
+        .iter()
+        .map(|x| x.map(|x| i256(ethnum::I256::new(x))))
+        .collect()
+}
+
+fn decimal256(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i256> {
+    to_i256(values).to(DataType::Decimal256(precision, scale))
+}
+
+#[test]
+fn test_add_normal() {
+    let a = decimal256(&[Some(111_11), Some(111_00), None, Some(-222_00)], 5, 2);
+    let b = decimal256(&[Some(222_22), Some(222_00), None, Some(111_00)], 5, 2);
+
+    let result = add(&a, &b);
+    let expected = decimal256(&[Some(333_33), Some(333_00), None, Some(-111_00)], 5, 2);
+
+    assert_eq!(result, expected);
+
+    // Testing trait
+    let result = a.add(&b);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic(expected = "Overflow in addition presented for precision 5")]
+fn test_add_panic() {
+    let a = decimal256(&[Some(99999)], 5, 2);
+    let b = decimal256(&[Some(1)], 5, 2);
+    let _ = add(&a, &b);
+}
+
+#[test]
+#[should_panic]
+fn test_add_wrong_precision() {
+    let a = decimal256(&[None], 5, 2);
+    let b = decimal256(&[None], 6, 2);
+    add(&a, &b);
+}
+
+#[test]
+fn test_add_saturating_checked() {
+    let a = decimal256(&[Some(99000), Some(-99000), None], 5, 2);
+    let b = decimal256(&[Some(01000), Some(-01000), None], 5, 2);
+
+    let result = saturating_add(&a, &b);
+    let expected = decimal256(&[Some(99999), Some(-99999), None], 5, 2);
+    assert_eq!(result, expected);
+
+    let result = checked_add(&a, &b);
+    let expected = decimal256(&[None, None, None], 5, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_beyond_i128() {
+    // 10^38 does not fit in a `Decimal` of precision 38
+    let big = i256(ethnum::I256::new(10).pow(38));
+    let a = PrimitiveArray::from([Some(big)]).to(DataType::Decimal256(76, 0));
+    let b = PrimitiveArray::from([Some(big)]).to(DataType::Decimal256(76, 0));
+
+    let result = add(&a, &b);
+    let expected = i256(ethnum::I256::new(2) * ethnum::I256::new(10).pow(38));
+    assert_eq!(result.value(0), expected);
+}
+
+#[test]
+fn test_adaptive_add() {
+    let a = decimal256(&[Some(11111_11), Some(99_99)], 7, 2);
+    let b = decimal256(&[Some(11111_111), Some(0_010)], 8, 3);
+
+    let result = adaptive_add(&a, &b).unwrap();
+    let expected = decimal256(&[Some(22222_221), Some(100_000)], 8, 3);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_adaptive_add_beyond_max_precision() {
+    let max = i256(ethnum::I256::new(10).pow(76) - 1);
+    let a = PrimitiveArray::from([Some(max)]).to(DataType::Decimal256(76, 0));
+
+    assert!(adaptive_add(&a, &a).is_err());
+}
+
+#[test]
+fn test_sub_normal() {
+    let a = decimal256(&[Some(111_11), None, Some(-222_00)], 5, 2);
+    let b = decimal256(&[Some(222_22), None, Some(111_00)], 5, 2);
+
+    let result = sub(&a, &b);
+    let expected = decimal256(&[Some(-111_11), None, Some(-333_00)], 5, 2);
+    assert_eq!(result, expected);
+
+    // Testing trait
+    let result = a.sub(&b);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_adaptive_sub() {
+    let a = decimal256(&[Some(11111_11)], 7, 2);
+    let b = decimal256(&[Some(-11111_111)], 8, 3);
+
+    let result = adaptive_sub(&a, &b).unwrap();
+    let expected = decimal256(&[Some(22222_221)], 8, 3);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul_normal() {
+    let a = decimal256(&[Some(111_11), Some(10_00), None, Some(-30_00)], 7, 2);
+    let b = decimal256(&[Some(222_22), Some(2_00), None, Some(4_00)], 7, 2);
+
+    let result = mul(&a, &b);
+    let expected = decimal256(&[Some(24690_86), Some(20_00), None, Some(-120_00)], 7, 2);
+    assert_eq!(result, expected);
+
+    // Testing trait
+    let result = a.mul(&b);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul_saturating_checked() {
+    let a = decimal256(&[Some(999_99), Some(1_00), None], 5, 2);
+    let b = decimal256(&[Some(10_00), Some(2_00), None], 5, 2);
+
+    let result = saturating_mul(&a, &b);
+    let expected = decimal256(&[Some(999_99), Some(2_00), None], 5, 2);
+    assert_eq!(result, expected);
+
+    let result = checked_mul(&a, &b);
+    let expected = decimal256(&[None, Some(2_00), None], 5, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul_saturating_overflow() {
+    // the products overflow `i256`
+    let big = i256(ethnum::I256::new(10).pow(40));
+    let a = PrimitiveArray::from([Some(big), Some(big), Some(i256(-big.0))])
+        .to(DataType::Decimal256(76, 0));
+    let b = PrimitiveArray::from([Some(big), Some(i256(-big.0)), Some(i256(-big.0))])
+        .to(DataType::Decimal256(76, 0));
+
+    let result = saturating_mul(&a, &b);
+    let max = i256(ethnum::I256::new(10).pow(76) - 1);
+    let expected = PrimitiveArray::from([Some(max), Some(i256(-max.0)), Some(max)])
+        .to(DataType::Decimal256(76, 0));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_mul_scalar() {
+    let a = decimal256(&[Some(1_50), None], 5, 2);
+    let b = PrimitiveScalar::new(DataType::Decimal256(5, 2), Some(i256(200.into())));
+
+    let result = mul_scalar(&a, &b);
+    let expected = decimal256(&[Some(3_00), None], 5, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_adaptive_mul() {
+    let a = decimal256(&[Some(11111_0), Some(1_0)], 6, 1);
+    let b = decimal256(&[Some(10_002), Some(2_000)], 5, 3);
+
+    let result = adaptive_mul(&a, &b).unwrap();
+    let expected = decimal256(&[Some(111132_222), Some(2_000)], 9, 3);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_div_normal() {
+    let a = decimal256(&[Some(222_222), Some(10_000), Some(-30_000)], 7, 3);
+    let b = decimal256(&[Some(123_456), Some(2_000), Some(4_000)], 7, 3);
+
+    let result = div(&a, &b);
+    let expected = decimal256(&[Some(1_800), Some(5_000), Some(-7_500)], 7, 3);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic(expected = "Found division by zero")]
+fn test_div_by_zero() {
+    let a = decimal256(&[Some(1_00)], 5, 2);
+    let b = decimal256(&[Some(0)], 5, 2);
+    let _ = div(&a, &b);
+}
+
+#[test]
+fn test_div_saturating_checked() {
+    let a = decimal256(&[Some(999_99), Some(4_00), Some(1_00)], 5, 2);
+    let b = decimal256(&[Some(000_01), Some(2_00), Some(0)], 5, 2);
+
+    let result = saturating_div(&a, &b);
+    let expected = decimal256(&[Some(999_99), Some(2_00), Some(0)], 5, 2);
+    assert_eq!(result, expected);
+
+    let result = a.checked_div(&b);
+    let expected = decimal256(&[None, Some(2_00), None], 5, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_adaptive_div() {
+    let a = decimal256(&[Some(1000_00)], 7, 2);
+    let b = decimal256(&[Some(10_0000)], 6, 4);
+
+    let result = adaptive_div(&a, &b).unwrap();
+    let expected = decimal256(&[Some(100_0000)], 9, 4);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_promote() {
+    let a = PrimitiveArray::from([Some(-1_00i128), None]).to(DataType::Decimal(5, 2));
+
+    let result = promote(&a).unwrap();
+    let expected = decimal256(&[Some(-1_00), None], 5, 2);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_promote_to_common() {
+    let a = decimal256(&[Some(1_5), None], 3, 1);
+    let b = decimal256(&[Some(-1_000), Some(2_000)], 5, 3);
+
+    let (a, b) = promote_to_common(&a, &b).unwrap();
+    assert_eq!(a, decimal256(&[Some(1_500), None], 5, 3));
+    assert_eq!(b, decimal256(&[Some(-1_000), Some(2_000)], 5, 3));
+
+    // 76 digits before the point and 76 after do not fit in a `Decimal256`
+    let a = decimal256(&[Some(1)], 76, 0);
+    let b = decimal256(&[Some(1)], 76, 76);
+    assert!(promote_to_common(&a, &b).is_err());
+}
+
+#[test]
+fn test_dyn() {
+    let a = decimal256(&[Some(1_00), None], 5, 2);
+    let b = decimal256(&[Some(2_00), Some(1_00)], 5, 2);
+    let c = PrimitiveArray::from([Some(2_00i128), Some(1_00)]).to(DataType::Decimal(5, 2));
+
+    assert!(arithmetics::can_add(a.data_type(), b.data_type()));
+    let result = arithmetics::add(&a, &b);
+    let expected = decimal256(&[Some(3_00), None], 5, 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // mixed `Decimal` and `Decimal256` results in a `Decimal256`
+    assert!(arithmetics::can_mul(a.data_type(), c.data_type()));
+    let result = arithmetics::mul(&c, &a);
+    let expected = decimal256(&[Some(2_00), None], 5, 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = arithmetics::sub(&a, &c);
+    let expected = decimal256(&[Some(-1_00), None], 5, 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // with different precisions and scales, both are promoted to a common one
+    let d = PrimitiveArray::from([Some(1_5i128), Some(2_5)]).to(DataType::Decimal(3, 1));
+    let result = arithmetics::add(&d, &a);
+    let expected = decimal256(&[Some(2_50), None], 5, 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = arithmetics::mul(&a, &d);
+    let expected = decimal256(&[Some(1_50), None], 5, 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
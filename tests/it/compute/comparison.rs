@@ -1,8 +1,9 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::comparison::{self, boolean::*, primitive, utf8};
-use arrow2::datatypes::{DataType, DataType::*, IntegerType, IntervalUnit, TimeUnit};
-use arrow2::scalar::new_scalar;
+use arrow2::datatypes::{DataType, DataType::*, Field, IntegerType, IntervalUnit, TimeUnit};
+use arrow2::scalar::{new_scalar, BooleanScalar, DictionaryScalar, PrimitiveScalar};
+use arrow2::types::{days_ms, f16, months_days_ns};
 
 #[test]
 fn consistency() {
@@ -42,7 +43,17 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Decimal(5, 2),
+        Decimal256(5, 2),
         Dictionary(IntegerType::Int32, Box::new(LargeBinary), false),
+        Dictionary(IntegerType::UInt8, Box::new(Float16), false),
+        List(Box::new(Field::new("item", Int32, true))),
+        LargeList(Box::new(Field::new("item", Utf8, true))),
+        FixedSizeList(Box::new(Field::new("item", Int32, true)), 2),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", List(Box::new(Field::new("item", Boolean, true))), true),
+        ]),
     ];
 
     // array <> array
@@ -414,3 +425,298 @@ fn primitive_and_validity() {
     let expected = BooleanArray::from_slice([false, true]);
     assert_eq!(primitive::eq_and_validity(&a1, &a2), expected);
 }
+
+#[test]
+fn interval_and_f16() {
+    let a = PrimitiveArray::from([Some(days_ms::new(1, 2)), Some(days_ms::new(1, 3)), None]);
+    let b = PrimitiveArray::from([Some(days_ms::new(1, 3)), Some(days_ms::new(1, 3)), None]);
+    let result = comparison::lt(&a, &b);
+    assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+
+    let a =
+        PrimitiveArray::from_slice([months_days_ns::new(1, 2, 3), months_days_ns::new(2, 0, 0)]);
+    let b =
+        PrimitiveArray::from_slice([months_days_ns::new(1, 2, 4), months_days_ns::new(1, 30, 0)]);
+    let result = comparison::gt_eq(&a, &b);
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+
+    let a = PrimitiveArray::from_slice([f16::from_f32(1.0), f16::from_f32(-2.0)]);
+    let b = PrimitiveArray::from_slice([f16::from_f32(1.5), f16::from_f32(-2.0)]);
+    assert_eq!(
+        comparison::lt(&a, &b),
+        BooleanArray::from_slice([true, false])
+    );
+    assert_eq!(
+        comparison::eq(&a, &b),
+        BooleanArray::from_slice([false, true])
+    );
+    let scalar = PrimitiveScalar::new(Float16, Some(f16::from_f32(0.0)));
+    assert_eq!(
+        comparison::gt_scalar(&a, &scalar),
+        BooleanArray::from_slice([true, false])
+    );
+}
+
+fn list(values: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(values).unwrap();
+    array.into()
+}
+
+#[test]
+fn list_eq() {
+    let a = list(vec![
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(1)]),
+    ]);
+    let b = list(vec![
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1), Some(3)]),
+        Some(vec![]),
+        Some(vec![]),
+        Some(vec![Some(1), Some(1)]),
+    ]);
+    assert!(comparison::can_eq(a.data_type()));
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), Some(true), None, Some(false)])
+    );
+
+    let result = comparison::neq_and_validity(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, true, false, true, true])
+    );
+
+    // slicing offsets the comparison
+    let result = comparison::eq(&a.sliced(1, 2), &b.sliced(1, 2));
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+}
+
+#[test]
+fn struct_eq() {
+    let data_type = Struct(vec![
+        Field::new("a", Int32, true),
+        Field::new("b", Utf8, true),
+    ]);
+    let a = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from([Some(1), Some(2), None]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c")]).boxed(),
+        ],
+        None,
+    );
+    let b = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from([Some(1), Some(2), None]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("c"), Some("c")]).boxed(),
+        ],
+        Some([true, true, false].into()),
+    );
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+
+    let result = comparison::eq_and_validity(&a, &b);
+    assert_eq!(result, BooleanArray::from_slice([true, false, false]));
+}
+
+#[test]
+fn fixed_size_list_eq() {
+    let data_type = FixedSizeList(Box::new(Field::new("item", Int32, true)), 2);
+    let a = FixedSizeListArray::new(
+        data_type.clone(),
+        Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed(),
+        None,
+    );
+    let b = FixedSizeListArray::new(
+        data_type,
+        Int32Array::from_slice([1, 2, 3, 5, 5, 6]).boxed(),
+        None,
+    );
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+}
+
+#[test]
+fn dictionary_and_values() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed();
+    let keys = PrimitiveArray::<i32>::from([Some(2), Some(0), None, Some(1)]);
+    let dict = DictionaryArray::try_from_keys(keys, values).unwrap();
+    let plain = Utf8Array::<i32>::from([Some("c"), Some("b"), Some("a"), None]);
+
+    let result = comparison::eq(&dict, &plain);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, None])
+    );
+
+    let result = comparison::lt(&dict, &plain);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), None, None])
+    );
+
+    let result = comparison::gt_eq(&plain, &dict);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(true), None, None])
+    );
+
+    // dictionaries with different keys
+    let values = Utf8Array::<i32>::from_slice(["c", "b"]).boxed();
+    let keys = PrimitiveArray::<u8>::from_slice([0, 1, 1, 0]);
+    let other = DictionaryArray::try_from_keys(keys, values).unwrap();
+
+    let result = comparison::neq_and_validity(&dict, &other);
+    assert_eq!(result, BooleanArray::from_slice([false, true, true, true]));
+}
+
+#[test]
+fn dictionary_scalar() {
+    let values = Int32Array::from_slice([10, 20]).boxed();
+    let keys = PrimitiveArray::<i32>::from([Some(1), Some(0), None]);
+    let dict = DictionaryArray::try_from_keys(keys, values).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), None]);
+
+    let scalar = PrimitiveScalar::new(Int32, Some(20i32));
+    assert_eq!(comparison::eq_scalar(&dict, &scalar), expected);
+
+    let scalar = DictionaryScalar::<i32>::new(dict.data_type().clone(), Some(Box::new(scalar)));
+    assert_eq!(comparison::eq_scalar(&dict, &scalar), expected);
+}
+
+#[test]
+fn distinct_from() {
+    let a = Int32Array::from([Some(1), None, None, Some(2)]);
+    let b = Int32Array::from([Some(1), None, Some(1), Some(3)]);
+    assert!(comparison::can_is_distinct_from(a.data_type()));
+
+    let result = comparison::is_not_distinct_from(&a, &b);
+    assert_eq!(result, BooleanArray::from_slice([true, true, false, false]));
+
+    let result = comparison::is_distinct_from(&a, &b);
+    assert_eq!(result, BooleanArray::from_slice([false, false, true, true]));
+}
+
+#[test]
+fn distinct_from_scalar() {
+    let a = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+
+    let scalar = new_scalar(&Utf8Array::<i32>::from_slice(["a"]), 0);
+    let result = comparison::is_not_distinct_from_scalar(&a, scalar.as_ref());
+    assert_eq!(result, BooleanArray::from_slice([true, false, false]));
+    let result = comparison::is_distinct_from_scalar(&a, scalar.as_ref());
+    assert_eq!(result, BooleanArray::from_slice([false, true, true]));
+
+    let scalar = new_scalar(&Utf8Array::<i32>::from([None::<&str>]), 0);
+    let result = comparison::is_not_distinct_from_scalar(&a, scalar.as_ref());
+    assert_eq!(result, BooleanArray::from_slice([false, true, false]));
+    let result = comparison::is_distinct_from_scalar(&a, scalar.as_ref());
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+}
+
+#[test]
+fn between() {
+    let array = Int32Array::from_iter((0..10).map(Some).chain(std::iter::once(None)));
+    let low = Int32Array::from_slice([0, 2, 2, 2, 2, 2, 2, 2, 9, 9, 0]);
+    let high = Int32Array::from([7, 3, 3, 3, 3, 3, 3, 8, 9, 10, 0].map(Some));
+    assert!(comparison::can_between(array.data_type()));
+
+    let result = comparison::between(&array, &low, &high, true).unwrap();
+    let expected = BooleanArray::from([
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(true),
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    let result = comparison::between(&array, &low, &high, false).unwrap();
+    let expected = BooleanArray::from([
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(false),
+        None,
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn between_scalar() {
+    let array = Int64Array::from_iter((0..10).map(Some).chain(std::iter::once(None)))
+        .to(Timestamp(TimeUnit::Second, None));
+    let low = PrimitiveScalar::new(Timestamp(TimeUnit::Second, None), Some(3i64));
+    let high = PrimitiveScalar::new(Timestamp(TimeUnit::Second, None), Some(8i64));
+
+    let result = comparison::between_scalar(&array, &low, &high, true).unwrap();
+    let expected = BooleanArray::from_iter(
+        (0..10)
+            .map(|x| Some((3..=8).contains(&x)))
+            .chain(std::iter::once(None)),
+    );
+    assert_eq!(result, expected);
+
+    let result = comparison::between_scalar(&array, &low, &high, false).unwrap();
+    let expected = BooleanArray::from_iter(
+        (0..10)
+            .map(|x| Some(x > 3 && x < 8))
+            .chain(std::iter::once(None)),
+    );
+    assert_eq!(result, expected);
+
+    let high = PrimitiveScalar::<i64>::new(Timestamp(TimeUnit::Second, None), None);
+    let result = comparison::between_scalar(&array, &low, &high, true).unwrap();
+    assert_eq!(result, BooleanArray::new_null(DataType::Boolean, 11));
+}
+
+#[test]
+fn between_not_supported() {
+    let array = BooleanArray::from_slice([true, false]);
+    assert!(!comparison::can_between(array.data_type()));
+    assert!(comparison::between(&array, &array, &array, true).is_err());
+
+    let scalar = BooleanScalar::from(Some(true));
+    assert!(comparison::between_scalar(&array, &scalar, &scalar, true).is_err());
+}
+
+#[test]
+fn between_utf8() {
+    let array = Utf8Array::<i32>::from([Some("arrow"), Some("flight"), Some("parquet"), None]);
+    let low = new_scalar(&Utf8Array::<i32>::from_slice(["b"]), 0);
+    let high = new_scalar(&Utf8Array::<i32>::from_slice(["flight"]), 0);
+
+    let result = comparison::between_scalar(&array, low.as_ref(), high.as_ref(), true).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), Some(false), None])
+    );
+
+    let result = comparison::between_scalar(&array, low.as_ref(), high.as_ref(), false).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(false), Some(false), None])
+    );
+}
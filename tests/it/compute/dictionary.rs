@@ -0,0 +1,172 @@
+use arrow2::array::*;
+use arrow2::compute::dictionary::*;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::error::Result;
+
+fn dictionary(keys: &[Option<i32>], values: &[Option<&str>]) -> DictionaryArray<i32> {
+    DictionaryArray::try_from_keys(
+        Int32Array::from(keys),
+        Utf8Array::<i32>::from(values).boxed(),
+    )
+    .unwrap()
+}
+
+fn decoded<K: DictionaryKey>(array: &DictionaryArray<K>) -> Vec<Option<String>> {
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    array
+        .keys_iter()
+        .map(|key| key.and_then(|key| values.get(key)).map(|x| x.to_string()))
+        .collect()
+}
+
+#[test]
+fn unify() -> Result<()> {
+    let a = dictionary(
+        &[Some(0), None, Some(1), Some(2)],
+        &[Some("b"), Some("a"), None],
+    );
+    let b = dictionary(&[Some(1), Some(0)], &[Some("c"), Some("b")]);
+
+    let unified = unify_dictionaries(&[&a, &b])?;
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), None]);
+    assert_eq!(unified[0].values().as_ref(), &expected as &dyn Array);
+    assert_eq!(unified[1].values().as_ref(), &expected as &dyn Array);
+    assert!(unified[0].is_ordered());
+
+    assert_eq!(decoded(&unified[0]), decoded(&a));
+    assert_eq!(decoded(&unified[1]), decoded(&b));
+    assert_eq!(
+        unified[0].keys(),
+        &Int32Array::from([Some(1), None, Some(0), Some(3)])
+    );
+    assert_eq!(unified[1].keys(), &Int32Array::from_slice([1, 2]));
+
+    assert!(unify_dictionaries::<i32>(&[])?.is_empty());
+    let c = DictionaryArray::try_from_keys(
+        Int32Array::from_slice([0]),
+        Int32Array::from_slice([1]).boxed(),
+    )?;
+    assert!(unify_dictionaries(&[&a, &c]).is_err());
+    Ok(())
+}
+
+#[test]
+fn compact_dictionary() -> Result<()> {
+    let array = dictionary(
+        &[Some(3), None, Some(1), Some(3)],
+        &[Some("a"), Some("b"), Some("c"), Some("d")],
+    );
+
+    let result = compact(&array)?;
+    let expected = Utf8Array::<i32>::from_slice(["b", "d"]);
+    assert_eq!(result.values().as_ref(), &expected as &dyn Array);
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from([Some(1), None, Some(0), Some(1)])
+    );
+    assert_eq!(decoded(&result), decoded(&array));
+
+    let result = compact(&array.sliced(1, 2))?;
+    let expected = Utf8Array::<i32>::from_slice(["b"]);
+    assert_eq!(result.values().as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sort() -> Result<()> {
+    let array = dictionary(
+        &[Some(0), Some(1), Some(2), None, Some(3)],
+        &[Some("c"), None, Some("a"), Some("c")],
+    );
+    assert!(!array.is_ordered());
+
+    let result = sort_dictionary(&array)?;
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("c"), None]);
+    assert_eq!(result.values().as_ref(), &expected as &dyn Array);
+    assert!(result.is_ordered());
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from([Some(1), Some(2), Some(0), None, Some(1)])
+    );
+    assert_eq!(decoded(&result), decoded(&array));
+    Ok(())
+}
+
+#[test]
+fn keys() -> Result<()> {
+    let array = dictionary(&[Some(1), None, Some(0)], &[Some("a"), Some("b")]);
+
+    let result = cast_keys::<i32, u8>(&array)?;
+    assert_eq!(
+        result.data_type(),
+        &DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false)
+    );
+    assert_eq!(result.keys(), &UInt8Array::from([Some(1), None, Some(0)]));
+    assert_eq!(result.values(), array.values());
+
+    let values = Utf8Array::<i32>::from_iter_values((0..300).map(|x| x.to_string()));
+    let array = DictionaryArray::try_from_keys(Int32Array::from_slice([299]), values.boxed())?;
+    assert!(cast_keys::<i32, i8>(&array).is_err());
+    assert!(cast_keys::<i32, i16>(&array).is_ok());
+    Ok(())
+}
+
+#[test]
+fn encode_utf8() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("b"), Some("a"), None, Some("b")]);
+
+    let result = dictionary_encode(&array, IntegerType::Int16)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i16>>()
+        .unwrap();
+    let expected = Utf8Array::<i64>::from_slice(["b", "a"]);
+    assert_eq!(result.values().as_ref(), &expected as &dyn Array);
+    assert_eq!(
+        result.keys(),
+        &Int16Array::from([Some(0), Some(1), None, Some(0)])
+    );
+    Ok(())
+}
+
+#[test]
+fn encode_primitive_and_binary() -> Result<()> {
+    let array = Int64Array::from([Some(3), Some(3), None, Some(1)]).to(DataType::Date64);
+    let result = dictionary_encode(&array, IntegerType::UInt32)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u32>>()
+        .unwrap();
+    let expected = Int64Array::from_slice([3, 1]).to(DataType::Date64);
+    assert_eq!(result.values().as_ref(), &expected as &dyn Array);
+    assert_eq!(
+        result.keys(),
+        &UInt32Array::from([Some(0), Some(0), None, Some(1)])
+    );
+
+    let array = BinaryArray::<i32>::from_slice([b"a", b"b", b"a"]);
+    let result = dictionary_encode(&array, IntegerType::Int8)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(result.keys(), &Int8Array::from_slice([0, 1, 0]));
+
+    assert!(dictionary_encode(&Float32Array::from_slice([1.0]), IntegerType::Int8).is_err());
+    Ok(())
+}
+
+#[test]
+fn encode_max_cardinality() -> Result<()> {
+    let array = UInt16Array::from_vec((0..300).collect());
+
+    assert!(dictionary_encode(&array, IntegerType::Int8).is_err());
+    assert!(dictionary_encode_with_max_cardinality(&array, IntegerType::Int32, 299)?.is_none());
+    let result = dictionary_encode_with_max_cardinality(&array, IntegerType::Int32, 300)?;
+    assert_eq!(result.unwrap().len(), 300);
+    Ok(())
+}
@@ -213,3 +213,62 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn fixed_size_binary() {
+    let a = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([5, 6])]);
+    let b = BooleanArray::from_slice([true, true, false]);
+    let c = filter(&a, &b).unwrap();
+
+    let expected = FixedSizeBinaryArray::from([Some([1, 2]), None]);
+    assert_eq!(expected, c.as_ref());
+}
+
+#[test]
+fn map() {
+    let mut a = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(vec![
+        Some(vec![(Some("a"), Some(1))]),
+        None,
+        Some(vec![(Some("b"), Some(2))]),
+    ])
+    .unwrap();
+    let a: MapArray = a.into();
+    let b = BooleanArray::from_slice([false, true, true]);
+    let c = filter(&a, &b).unwrap();
+
+    assert_eq!(a.sliced(1, 2), c.as_ref());
+}
+
+#[test]
+fn union() {
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let a = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from_slice([1, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["b"]).boxed(),
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    let b = BooleanArray::from_slice([false, true, true]);
+    let c = filter(&a, &b).unwrap();
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![1, 0].into(),
+        vec![
+            Int32Array::from_slice([3]).boxed(),
+            Utf8Array::<i32>::from_slice(["b"]).boxed(),
+        ],
+        Some(vec![0, 0].into()),
+    );
+    assert_eq!(expected, c.as_ref());
+}
@@ -40,3 +40,65 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn coalesce() -> Result<()> {
+    use arrow2::compute::if_then_else::coalesce;
+
+    let a = Utf8Array::<i32>::from([Some("a"), None, None, None]);
+    let b = Utf8Array::<i32>::from([Some("b"), Some("b"), None, None]);
+    let c = Utf8Array::<i32>::from([None, None, Some("c"), None]);
+    let result = coalesce(&[&a, &b, &c])?;
+
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), None]);
+    assert_eq!(expected, result.as_ref());
+
+    // arrays of length 1 are broadcasted
+    let default = Utf8Array::<i32>::from_slice(["z"]);
+    let result = coalesce(&[&a, &default])?;
+
+    let expected = Utf8Array::<i32>::from_slice(["a", "z", "z", "z"]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn coalesce_errors() {
+    use arrow2::compute::if_then_else::coalesce;
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int64Array::from_slice([1, 2]);
+    let c = Int32Array::from_slice([1, 2, 3]);
+    assert!(coalesce(&[]).is_err());
+    assert!(coalesce(&[&a, &b]).is_err());
+    assert!(coalesce(&[&a, &c]).is_err());
+}
+
+#[test]
+fn case_when() -> Result<()> {
+    use arrow2::compute::if_then_else::case_when;
+
+    let p1 = BooleanArray::from([Some(true), Some(false), None, Some(false), Some(true)]);
+    let p2 = BooleanArray::from([Some(true), Some(true), Some(true), None, Some(false)]);
+    let v1 = Int32Array::from([Some(1), Some(2), Some(3), Some(4), None]);
+    let v2 = Int32Array::from_slice([10]);
+    let otherwise = Int32Array::from([None]);
+    let result = case_when(&[(&p1, &v1), (&p2, &v2)], &otherwise)?;
+
+    let expected = Int32Array::from([Some(1), Some(10), Some(10), None, None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn case_when_errors() {
+    use arrow2::compute::if_then_else::case_when;
+
+    let p1 = BooleanArray::from_slice([true, false]);
+    let p2 = BooleanArray::from_slice([true]);
+    let v = Int32Array::from_slice([1, 2]);
+    let other = Int64Array::from_slice([1, 2]);
+    assert!(case_when(&[], &v).is_err());
+    assert!(case_when(&[(&p1, &v), (&p2, &v)], &v).is_err());
+    assert!(case_when(&[(&p1, &v)], &other).is_err());
+}
@@ -0,0 +1,63 @@
+use arrow2::array::*;
+use arrow2::compute::interleave::interleave;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let a = Int64Array::from_slice([1, 2, 3]);
+    let b = Int64Array::from_slice([4, 5]);
+
+    let result = interleave(&[&a, &b], &[(0, 0), (1, 1), (0, 2)])?;
+    assert_eq!(
+        result.as_ref(),
+        &Int64Array::from_slice([1, 5, 3]) as &dyn Array
+    );
+
+    let result = interleave(&[&a, &b], &[])?;
+    assert_eq!(result.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let a = Utf8Array::<i32>::from([Some("a"), None]);
+    let b = Utf8Array::<i32>::from_slice(["bb", "ccc"]);
+
+    let result = interleave(&[&a, &b], &[(1, 1), (0, 1), (0, 0), (1, 0)])?;
+    let expected = Utf8Array::<i32>::from([Some("ccc"), None, Some("a"), Some("bb")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn nested() -> Result<()> {
+    let a = Int32Array::from([Some(1), None, Some(3)]);
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let lhs = StructArray::new(DataType::Struct(fields.clone()), vec![a.boxed()], None);
+    let b = Int32Array::from_slice([4]);
+    let rhs = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![b.boxed()],
+        Some([false].into()),
+    );
+
+    let result = interleave(&[&lhs, &rhs], &[(0, 2), (1, 0), (0, 1)])?;
+    let expected = StructArray::new(
+        DataType::Struct(fields),
+        vec![Int32Array::from([Some(3), Some(4), None]).boxed()],
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let a = Int32Array::from_slice([1]);
+    let b = Int64Array::from_slice([1]);
+    assert!(interleave(&[], &[]).is_err());
+    assert!(interleave(&[&a, &b], &[(0, 0)]).is_err());
+    assert!(interleave(&[&a], &[(0, 1)]).is_err());
+    assert!(interleave(&[&a], &[(1, 0)]).is_err());
+}
@@ -0,0 +1,221 @@
+use arrow2::array::*;
+use arrow2::compute::list::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::offset::Offset;
+
+fn list<O: Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn data() -> Vec<Option<Vec<Option<i32>>>> {
+    vec![
+        Some(vec![Some(3), None, Some(1), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2)]),
+    ]
+}
+
+fn fixed_size_list() -> FixedSizeListArray {
+    let values = Int32Array::from([Some(3), Some(1), None, Some(2), Some(5), Some(4)]);
+    FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(DataType::Int32, 2),
+        values.boxed(),
+        Some([true, false, true].into()),
+    )
+}
+
+#[test]
+fn flatten_and_explode() -> Result<()> {
+    let array = list::<i32>(data());
+
+    let (values, parents) = flatten(&array)?;
+    let expected = Int32Array::from([Some(3), None, Some(1), Some(3), Some(2)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(parents.values().as_slice(), &[0, 0, 0, 0, 3]);
+
+    let (values, parents) = explode(&array)?;
+    let expected = Int32Array::from([Some(3), None, Some(1), Some(3), None, None, Some(2)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(parents.values().as_slice(), &[0, 0, 0, 0, 1, 2, 3]);
+
+    let (values, parents) = flatten(&fixed_size_list())?;
+    let expected = Int32Array::from([Some(3), Some(1), Some(5), Some(4)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(parents.values().as_slice(), &[0, 0, 2, 2]);
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = list::<i64>(data()).sliced(1, 3);
+
+    let (values, parents) = flatten(&array)?;
+    assert_eq!(values.as_ref(), &Int32Array::from_slice([2]) as &dyn Array);
+    assert_eq!(parents.values().as_slice(), &[2]);
+
+    let result = list_length(&array)?;
+    let expected = Int64Array::from([None, Some(0), Some(1)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn length() -> Result<()> {
+    let result = list_length(&list::<i32>(data()))?;
+    let expected = Int32Array::from([Some(4), None, Some(0), Some(1)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_length(&fixed_size_list())?;
+    let expected = Int32Array::from([Some(2), None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn get() -> Result<()> {
+    let array = list::<i32>(data());
+
+    let result = list_get(&array, 0)?;
+    let expected = Int32Array::from([Some(3), None, None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_get(&array, -1)?;
+    let expected = Int32Array::from([Some(3), None, None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_get(&array, 2)?;
+    let expected = Int32Array::from([Some(1), None, None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_get(&fixed_size_list(), -2)?;
+    let expected = Int32Array::from([Some(3), None, Some(5)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn slice() -> Result<()> {
+    let array = list::<i32>(data());
+
+    let result = list_slice(&array, 1, Some(-1))?;
+    let expected = list::<i32>(vec![
+        Some(vec![None, Some(1)]),
+        None,
+        Some(vec![]),
+        Some(vec![]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_slice(&array, -2, None)?;
+    let expected = list::<i32>(vec![
+        Some(vec![Some(1), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_slice(&fixed_size_list(), 1, Some(10))?;
+    let expected = ListArray::<i32>::new(
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        vec![0, 1, 1, 2].try_into().unwrap(),
+        Int32Array::from_slice([1, 4]).boxed(),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn aggregates() -> Result<()> {
+    let array = list::<i32>(data());
+
+    let result = list_sum(&array)?;
+    let expected = Int32Array::from([Some(7), None, None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_min(&array)?;
+    let expected = Int32Array::from([Some(1), None, None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_max(&array)?;
+    let expected = Int32Array::from([Some(3), None, None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_mean(&array)?;
+    let expected = Float64Array::from([Some(7.0 / 3.0), None, None, Some(2.0)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_sum(&fixed_size_list())?;
+    let expected = Int32Array::from([Some(4), None, Some(9)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = ListArray::<i32>::new(
+        DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+        vec![0, 1].try_into().unwrap(),
+        Utf8Array::<i32>::from_slice(["a"]).boxed(),
+        None,
+    );
+    assert!(list_sum(&array).is_err());
+    assert!(list_sum(&Int32Array::from_slice([1])).is_err());
+    Ok(())
+}
+
+#[test]
+fn sum_overflow() -> Result<()> {
+    let array = list::<i32>(vec![Some(vec![Some(i32::MAX), Some(2)])]);
+
+    let result = list_sum(&array)?;
+    let expected = Int32Array::from_slice([i32::MIN.wrapping_add(1)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sort() -> Result<()> {
+    let array = list::<i32>(data());
+
+    let result = list_sort(&array, false)?;
+    let expected = list::<i32>(vec![
+        Some(vec![Some(1), Some(3), Some(3), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_sort(&array, true)?;
+    let expected = list::<i32>(vec![
+        Some(vec![Some(3), Some(3), Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = list_sort(&fixed_size_list(), true)?;
+    let expected = FixedSizeListArray::new(
+        FixedSizeListArray::default_datatype(DataType::Int32, 2),
+        Int32Array::from([Some(3), Some(1), Some(2), None, Some(5), Some(4)]).boxed(),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn unique() -> Result<()> {
+    let result = list_unique(&list::<i64>(data()))?;
+    let expected = list::<i64>(vec![
+        Some(vec![Some(1), Some(3), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
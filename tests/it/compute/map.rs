@@ -0,0 +1,107 @@
+use arrow2::array::*;
+use arrow2::compute::map::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+fn utf8_map() -> MapArray {
+    let mut map = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    map.try_extend(vec![
+        Some(vec![(Some("env"), Some(1)), (Some("app"), None)]),
+        None,
+        Some(vec![]),
+        Some(vec![(Some("app"), Some(3)), (Some("env"), Some(4))]),
+    ])
+    .unwrap();
+    map.into()
+}
+
+#[test]
+fn get_utf8() -> Result<()> {
+    let map = utf8_map();
+
+    let result = map_get(&map, &Utf8Scalar::<i32>::from(Some("env")))?;
+    let expected = Int32Array::from([Some(1), None, None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_get(&map, &Utf8Scalar::<i32>::from(Some("app")))?;
+    let expected = Int32Array::from([None, None, None, Some(3)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_get(
+        &map.clone().sliced(2, 2),
+        &Utf8Scalar::<i32>::from(Some("env")),
+    )?;
+    let expected = Int32Array::from([None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_get(&map, &Utf8Scalar::<i32>::new(None::<&str>))?;
+    assert_eq!(result.null_count(), 4);
+
+    assert!(map_get(&map, &Utf8Scalar::<i64>::from(Some("env"))).is_err());
+    Ok(())
+}
+
+#[test]
+fn contains_key() -> Result<()> {
+    let map = utf8_map();
+
+    let result = map_contains_key(&map, &Utf8Scalar::<i32>::from(Some("app")))?;
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = map_contains_key(&map, &Utf8Scalar::<i32>::new(None::<&str>))?;
+    assert_eq!(result, BooleanArray::new_null(DataType::Boolean, 4));
+    Ok(())
+}
+
+#[test]
+fn get_integer() -> Result<()> {
+    let mut map = MutableMapArray::<MutablePrimitiveArray<u8>, MutableUtf8Array<i32>>::new();
+    map.try_extend(vec![
+        Some(vec![(Some(1u8), Some("a")), (Some(2), Some("b"))]),
+        Some(vec![(Some(2), Some("c"))]),
+    ])?;
+    let map: MapArray = map.into();
+
+    let key = PrimitiveScalar::<u8>::new(DataType::UInt8, Some(2));
+    let result = map_get(&map, &key)?;
+    let expected = Utf8Array::<i32>::from_slice(["b", "c"]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_contains_key(&map, &PrimitiveScalar::<u8>::new(DataType::UInt8, Some(1)))?;
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+    Ok(())
+}
+
+#[test]
+fn keys_values_from_arrays() -> Result<()> {
+    let map = utf8_map();
+
+    let keys = map_keys(&map);
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected.try_extend(vec![
+        Some(vec![Some("env"), Some("app")]),
+        None,
+        Some(vec![]),
+        Some(vec![Some("app"), Some("env")]),
+    ])?;
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(keys.offsets(), expected.offsets());
+    assert_eq!(keys.values(), expected.values());
+    assert_eq!(keys.validity(), expected.validity());
+
+    let values = map_values(&map);
+    let expected = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    assert_eq!(values.values().as_ref(), &expected as &dyn Array);
+
+    let result = map_from_arrays(&keys, &values)?;
+    assert_eq!(result, map);
+
+    let result = map_from_arrays(&keys.clone().sliced(3, 1), &values.clone().sliced(3, 1))?;
+    assert_eq!(result, map.sliced(3, 1));
+
+    assert!(map_from_arrays(&keys.clone().sliced(0, 1), &values.clone().sliced(2, 1)).is_err());
+    assert!(map_from_arrays(&keys.sliced(0, 2), &values).is_err());
+    Ok(())
+}
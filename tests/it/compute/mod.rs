@@ -16,26 +16,40 @@ mod comparison;
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_dictionary")]
+mod dictionary;
 #[cfg(feature = "compute_filter")]
 mod filter;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+mod interleave;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
+#[cfg(feature = "compute_map")]
+mod map;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_repeat")]
+mod repeat;
+#[cfg(feature = "compute_selection")]
+mod selection;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_struct")]
+mod struct_;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
@@ -0,0 +1,107 @@
+use arrow2::array::*;
+use arrow2::compute::repeat::*;
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::error::Result;
+use arrow2::scalar::new_scalar;
+
+fn arrays() -> Result<Vec<Box<dyn Array>>> {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![Some(vec![Some(1), None]), None])?;
+    let list: ListArray<i32> = list.into();
+
+    let mut fixed_size_list = MutableFixedSizeListArray::new(MutableUtf8Array::<i32>::new(), 2);
+    fixed_size_list.try_extend(vec![Some(vec![Some("a"), None]), None])?;
+    let fixed_size_list: FixedSizeListArray = fixed_size_list.into();
+
+    let mut map = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    map.try_extend(vec![
+        Some(vec![(Some("a"), Some(1)), (Some("b"), None)]),
+        None,
+    ])?;
+    let map: MapArray = map.into();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let struct_ = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Int32Array::from([Some(1), None]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        Some([true, false].into()),
+    );
+    let sparse = UnionArray::new(
+        DataType::Union(fields.clone(), Some(vec![3, 5]), UnionMode::Sparse),
+        vec![5, 3].into(),
+        vec![
+            Int32Array::from([None, Some(2)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+        ],
+        None,
+    );
+    let dense = UnionArray::new(
+        DataType::Union(fields, None, UnionMode::Dense),
+        vec![1, 0].into(),
+        vec![
+            Int32Array::from([Some(2)]).boxed(),
+            Utf8Array::<i32>::from([Some("a")]).boxed(),
+        ],
+        Some(vec![0, 0].into()),
+    );
+
+    let dictionary = DictionaryArray::try_from_keys(
+        Int8Array::from([Some(1), None]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )?;
+
+    Ok(vec![
+        NullArray::new(DataType::Null, 2).boxed(),
+        BooleanArray::from([Some(true), None]).boxed(),
+        Int64Array::from([Some(1), None]).boxed(),
+        Utf8Array::<i64>::from([Some("abc"), None]).boxed(),
+        BinaryArray::<i32>::from([Some(b"abc"), None]).boxed(),
+        FixedSizeBinaryArray::from([Some([1, 2]), None]).boxed(),
+        list.boxed(),
+        fixed_size_list.boxed(),
+        map.boxed(),
+        struct_.boxed(),
+        sparse.boxed(),
+        dense.boxed(),
+        dictionary.boxed(),
+    ])
+}
+
+#[test]
+fn repeat_scalars() -> Result<()> {
+    for array in arrays()? {
+        for index in 0..array.len() {
+            let scalar = new_scalar(array.as_ref(), index);
+            let result = repeat(scalar.as_ref(), 3)?;
+            let expected = take(
+                array.as_ref(),
+                &UInt64Array::from_vec(vec![index as u64; 3]),
+            )?;
+            assert_eq!(result, expected, "{:?}", array.data_type());
+
+            assert_eq!(repeat(scalar.as_ref(), 0)?.len(), 0);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn repeat_each_slot() -> Result<()> {
+    for array in arrays()? {
+        let result = repeat_each(array.as_ref(), &[2, 1])?;
+        let expected = take(array.as_ref(), &UInt64Array::from_slice([0, 0, 1]))?;
+        assert_eq!(result, expected, "{:?}", array.data_type());
+    }
+
+    let array = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert_eq!(repeat_each(&array, &[0, 0])?.len(), 0);
+    assert!(repeat_each(&array, &[1]).is_err());
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::selection::SelectionVector;
+use arrow2::error::Result;
+
+#[test]
+fn from_mask_and_indices() -> Result<()> {
+    let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    let selection = SelectionVector::from_mask(&mask);
+    assert_eq!(selection.num_rows(), 4);
+    assert_eq!(selection.selected(), 2);
+    assert_eq!(selection.selectivity(), 0.5);
+    assert_eq!(selection.to_indices(), UInt64Array::from_slice([0, 3]));
+
+    let selection = SelectionVector::try_from_indices(vec![1, 2], 4)?;
+    assert_eq!(
+        selection.to_mask(),
+        BooleanArray::from_slice([false, true, true, false])
+    );
+
+    assert!(SelectionVector::try_from_indices(vec![2, 1], 4).is_err());
+    assert!(SelectionVector::try_from_indices(vec![1, 1], 4).is_err());
+    assert!(SelectionVector::try_from_indices(vec![4], 4).is_err());
+    Ok(())
+}
+
+#[test]
+fn and_or() -> Result<()> {
+    let mask = SelectionVector::from_mask(&BooleanArray::from_slice([
+        true, true, false, false, true, false,
+    ]));
+    let other_mask = SelectionVector::from_mask(&BooleanArray::from_slice([
+        false, true, true, false, false, false,
+    ]));
+    let indices = SelectionVector::try_from_indices(vec![0, 3, 4], 6)?;
+    let other_indices = SelectionVector::try_from_indices(vec![3, 5], 6)?;
+
+    let cases = [
+        (&mask, &other_mask, vec![1], vec![0, 1, 2, 4]),
+        (&indices, &other_indices, vec![3], vec![0, 3, 4, 5]),
+        (&mask, &indices, vec![0, 4], vec![0, 1, 3, 4]),
+        (&other_indices, &other_mask, vec![], vec![1, 2, 3, 5]),
+    ];
+    for (lhs, rhs, and, or) in cases {
+        assert_eq!(lhs.and(rhs)?.to_indices(), UInt64Array::from_vec(and));
+        assert_eq!(lhs.or(rhs)?.to_indices(), UInt64Array::from_vec(or));
+    }
+
+    let other = SelectionVector::try_from_indices(vec![], 5)?;
+    assert!(mask.and(&other).is_err());
+    assert!(mask.or(&other).is_err());
+    Ok(())
+}
+
+#[test]
+fn apply() -> Result<()> {
+    let array = Int32Array::from_iter((0..100).map(Some));
+    let utf8 = Utf8Array::<i32>::from_iter((0..100).map(|x| Some(x.to_string())));
+    let chunk = Chunk::new(vec![array.clone().boxed(), utf8.boxed()]);
+
+    // sparse selections are taken, dense selections are filtered
+    let sparse = SelectionVector::try_from_indices(vec![3, 50], 100)?;
+    let dense =
+        SelectionVector::from_mask(&BooleanArray::from_iter((0..100).map(|x| Some(x % 2 == 0))));
+    for (selection, expected) in [
+        (&sparse, vec![3, 50]),
+        (&dense, (0..100).step_by(2).collect::<Vec<_>>()),
+    ] {
+        let result = selection.apply(&array)?;
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from_vec(expected.clone()) as &dyn Array
+        );
+
+        let result = selection.apply_chunk(&chunk)?;
+        assert_eq!(result.len(), expected.len());
+        let strings = expected.iter().map(|x| Some(x.to_string()));
+        assert_eq!(
+            result.arrays()[1].as_ref(),
+            &Utf8Array::<i32>::from_iter(strings) as &dyn Array
+        );
+    }
+
+    assert!(sparse.apply(&array.sliced(0, 10)).is_err());
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::struct_::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+fn array() -> StructArray {
+    let c = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    let b = StructArray::new(
+        DataType::Struct(vec![Field::new("c", DataType::Int32, true)]),
+        vec![c.boxed()],
+        Some(Bitmap::from([true, true, true, false])),
+    );
+    let d = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    StructArray::new(
+        DataType::Struct(vec![
+            Field::new("b", b.data_type().clone(), true),
+            Field::new("d", DataType::Utf8, false),
+        ]),
+        vec![b.boxed(), d.boxed()],
+        Some(Bitmap::from([true, true, false, true])),
+    )
+}
+
+#[test]
+fn field_path() -> Result<()> {
+    let array = array();
+
+    let result = get_field_path(&array, &["b", "c"])?;
+    let expected = Int32Array::from([Some(1), None, None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field_path(&array, &["d"])?;
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field_path(&array.clone().sliced(1, 2), &["b", "c"])?;
+    let expected = Int32Array::from([None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(get_field_path(&array, &[]).is_err());
+    assert!(get_field_path(&array, &["x"]).is_err());
+    assert!(get_field_path(&array, &["d", "x"]).is_err());
+    Ok(())
+}
+
+#[test]
+fn add_drop_rename() -> Result<()> {
+    let array = array();
+    let e = Int64Array::from_slice([1, 2, 3, 4]).boxed();
+
+    let result = with_field(&array, Field::new("e", DataType::Int64, false), e.clone())?;
+    let names = result
+        .fields()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["b", "d", "e"]);
+    assert_eq!(result.values()[2], e);
+    assert_eq!(result.validity(), array.validity());
+
+    let result = with_field(&array, Field::new("d", DataType::Int64, false), e.clone())?;
+    let names = result
+        .fields()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["b", "d"]);
+    assert_eq!(result.values()[1], e);
+
+    assert!(with_field(&array, Field::new("e", DataType::Int32, false), e.clone()).is_err());
+    assert!(with_field(
+        &array,
+        Field::new("e", DataType::Int64, false),
+        e.sliced(0, 2)
+    )
+    .is_err());
+
+    let result = drop_field(&array, "b")?;
+    assert_eq!(result.fields(), &[Field::new("d", DataType::Utf8, false)]);
+    assert_eq!(result.values(), &array.values()[1..]);
+    assert!(drop_field(&result, "d").is_err());
+    assert!(drop_field(&array, "x").is_err());
+
+    let result = rename_fields(&array, &["x", "y"])?;
+    let names = result
+        .fields()
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["x", "y"]);
+    assert_eq!(result.values(), array.values());
+    assert!(rename_fields(&array, &["x"]).is_err());
+    Ok(())
+}
+
+#[test]
+fn unnest_struct() {
+    let array = array();
+    let chunk = unnest(&array);
+
+    assert_eq!(chunk.arrays().len(), 2);
+    assert_eq!(
+        chunk.arrays()[0].validity(),
+        Some(&Bitmap::from([true, true, false, false]))
+    );
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]);
+    assert_eq!(chunk.arrays()[1].as_ref(), &expected as &dyn Array);
+}
@@ -1,5 +1,5 @@
 use arrow2::compute::take::{can_take, take};
-use arrow2::datatypes::{DataType, Field, IntervalUnit};
+use arrow2::datatypes::{DataType, Field, IntervalUnit, UnionMode};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
 use arrow2::{bitmap::Bitmap, buffer::Buffer};
@@ -135,6 +135,7 @@ fn consistency() {
         LargeUtf8,
         Binary,
         LargeBinary,
+        FixedSizeBinary(4),
         Duration(TimeUnit::Second),
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
@@ -324,3 +325,107 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn fixed_size_binary() {
+    let values = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([5, 6])]);
+    let indices = Int32Array::from(&[Some(2), None, Some(1), Some(0)]);
+    let result = take(&values, &indices).unwrap();
+
+    let expected = FixedSizeBinaryArray::from([Some([5, 6]), None, None, Some([1, 2])]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn map() {
+    let mut values = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    values
+        .try_extend(vec![
+            Some(vec![(Some("a"), Some(1))]),
+            None,
+            Some(vec![(Some("b"), Some(2)), (Some("c"), None)]),
+        ])
+        .unwrap();
+    let values: MapArray = values.into();
+
+    let indices = Int32Array::from(&[Some(2), None, Some(1), Some(0)]);
+    let result = take(&values, &indices).unwrap();
+
+    let mut expected = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    expected
+        .try_extend(vec![
+            Some(vec![(Some("b"), Some(2)), (Some("c"), None)]),
+            None,
+            None,
+            Some(vec![(Some("a"), Some(1))]),
+        ])
+        .unwrap();
+    let expected: MapArray = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
+
+fn union_fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]
+}
+
+#[test]
+fn union_sparse() -> Result<()> {
+    let data_type = DataType::Union(union_fields(), None, UnionMode::Sparse);
+    let values = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0, 1].into(),
+        vec![
+            Int32Array::from(&[Some(1), None, Some(3), None]).boxed(),
+            Utf8Array::<i32>::from([None, Some("b"), None, Some("d")]).boxed(),
+        ],
+        None,
+    )
+    .sliced(1, 3);
+
+    let indices = Int32Array::from(&[Some(2), None, Some(1), Some(0)]);
+    let result = take(&values, &indices)?;
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![1, 0, 0, 1].into(),
+        vec![
+            Int32Array::from(&[None, None, Some(3), None]).boxed(),
+            Utf8Array::<i32>::from([Some("d"), None, None, Some("b")]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn union_dense() -> Result<()> {
+    let data_type = DataType::Union(union_fields(), None, UnionMode::Dense);
+    let values = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0, 1].into(),
+        vec![
+            Int32Array::from(&[Some(1), Some(3)]).boxed(),
+            Utf8Array::<i32>::from([Some("b"), Some("d")]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    );
+
+    let indices = Int32Array::from(&[Some(3), None, Some(2), Some(1), Some(3)]);
+    let result = take(&values, &indices)?;
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![1, 0, 0, 1, 1].into(),
+        vec![
+            Int32Array::from(&[None, Some(3)]).boxed(),
+            Utf8Array::<i32>::from([Some("d"), Some("b"), Some("d")]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1, 2].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::datatypes::{DataType, Field, Schema, UnionMode};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_file_metadata;
 use std::sync::Arc;
@@ -120,3 +120,47 @@ fn dict() -> Result<()> {
 
     round_trip(array)
 }
+
+#[test]
+fn map() -> Result<()> {
+    let mut array = MutableMapArray::<MutableUtf8Array<i32>, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![(Some("a"), Some(1)), (Some("b"), None)]),
+        None,
+        Some(vec![(Some("c"), Some(3))]),
+    ])?;
+    let array: MapArray = array.into();
+    round_trip(array.sliced(1, 2).boxed())
+}
+
+#[test]
+fn union() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    let data_type = DataType::Union(fields.clone(), None, UnionMode::Sparse);
+    let array = UnionArray::new(
+        data_type,
+        vec![0, 1, 1].into(),
+        vec![
+            Int32Array::from(&[Some(1), None, None]).boxed(),
+            Utf8Array::<i32>::from([None, Some("b"), Some("c")]).boxed(),
+        ],
+        None,
+    );
+    round_trip(array.boxed())?;
+
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let array = UnionArray::new(
+        data_type,
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from(&[Some(1), None]).boxed(),
+            Utf8Array::<i32>::from([Some("b")]).boxed(),
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    round_trip(array.boxed())
+}
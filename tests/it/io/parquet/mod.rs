@@ -17,6 +17,7 @@ use arrow2::{
 mod deserialize;
 #[cfg(feature = "io_json_integration")]
 mod integration;
+mod predicate;
 mod read;
 mod read_indexes;
 mod write;
@@ -1798,6 +1799,7 @@ fn integration_write(schema: &Schema, chunks: &[Chunk<Box<dyn Array>>]) -> Resul
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let encodings = schema
@@ -2245,6 +2247,88 @@ fn nested_dict_limit() -> Result<()> {
     assert_roundtrip(schema, chunk, Some(2))
 }
 
+fn union_data(mode: UnionMode, ids: Option<Vec<i32>>) -> Result<Box<dyn Array>> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, ids.clone(), mode);
+    let (a, b) = if mode.is_sparse() {
+        (
+            Int32Array::from([Some(1), None, None, Some(4)]),
+            Utf8Array::<i32>::from([None, Some("b"), Some("c"), None]),
+        )
+    } else {
+        (
+            Int32Array::from([Some(1), None]),
+            Utf8Array::<i32>::from_slice(["b", "c"]),
+        )
+    };
+    let offsets = (!mode.is_sparse()).then(|| vec![0, 0, 1, 1].into());
+    let ids = ids.unwrap_or_else(|| vec![0, 1]);
+    let types = [0, 1, 1, 0].map(|x| ids[x] as i8).to_vec();
+    Ok(UnionArray::try_new(data_type, types.into(), vec![a.boxed(), b.boxed()], offsets)?.boxed())
+}
+
+#[test]
+fn union_sparse() -> Result<()> {
+    assert_array_roundtrip(true, union_data(UnionMode::Sparse, None)?, None)
+}
+
+#[test]
+fn union_dense() -> Result<()> {
+    assert_array_roundtrip(false, union_data(UnionMode::Dense, Some(vec![3, 7]))?, None)
+}
+
+#[test]
+fn union_sliced() -> Result<()> {
+    let array = union_data(UnionMode::Dense, None)?.sliced(1, 3);
+    assert_array_roundtrip(true, array, None)?;
+    let array = union_data(UnionMode::Sparse, None)?.sliced(1, 3);
+    assert_array_roundtrip(true, array, None)
+}
+
+#[test]
+fn union_null_variant() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let types = vec![7, 3, 3, 7];
+
+    // nulls of the second variant are read as such
+    let data_type = DataType::Union(fields.clone(), Some(vec![3, 7]), UnionMode::Sparse);
+    let a = Int32Array::from([None, None, Some(1), None]);
+    let b = Utf8Array::<i32>::from([None, Some("b"), None, Some("d")]);
+    let array = UnionArray::try_new(
+        data_type,
+        types.clone().into(),
+        vec![a.boxed(), b.boxed()],
+        None,
+    )?;
+    assert_array_roundtrip(true, array.boxed(), None)?;
+
+    let data_type = DataType::Union(fields, Some(vec![3, 7]), UnionMode::Dense);
+    let a = Int32Array::from([Some(1), None]);
+    let b = Utf8Array::<i32>::from([None::<&str>, None]);
+    let offsets = Some(vec![0, 0, 1, 1].into());
+    let array = UnionArray::try_new(data_type, types.into(), vec![a.boxed(), b.boxed()], offsets)?;
+    assert_array_roundtrip(true, array.boxed(), None)
+}
+
+#[test]
+fn list_union() -> Result<()> {
+    let values = union_data(UnionMode::Dense, None)?;
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let array = ListArray::<i32>::try_new(
+        data_type,
+        vec![0, 1, 1, 4].try_into()?,
+        values,
+        Some([true, false, true].into()),
+    )?;
+    assert_array_roundtrip(true, array.boxed(), None)
+}
+
 #[test]
 fn filter_chunk() -> Result<()> {
     let chunk1 = Chunk::new(vec![PrimitiveArray::from_slice([1i16, 3]).boxed()]);
@@ -2276,3 +2360,160 @@ fn filter_chunk() -> Result<()> {
     assert_eq!(new_chunks, vec![chunk1]);
     Ok(())
 }
+
+#[test]
+fn nested_statistics() -> Result<()> {
+    let dictionary = |values: &[&str]| {
+        let mut array = MutableDictionaryArray::<i8, MutableUtf8Array<i32>>::new();
+        array.try_extend(values.iter().map(Some)).unwrap();
+        DictionaryArray::from(array).boxed()
+    };
+    let struct_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("d", dictionary(&[]).data_type().clone(), false),
+    ]);
+    let list_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let entries_type = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let map_type = DataType::Map(
+        Box::new(Field::new("entries", entries_type.clone(), false)),
+        false,
+    );
+    let entries = |keys: [&str; 2], values: [Option<i32>; 2]| {
+        StructArray::new(
+            entries_type.clone(),
+            vec![
+                Utf8Array::<i32>::from_slice(keys).boxed(),
+                Int32Array::from(values).boxed(),
+            ],
+            None,
+        )
+        .boxed()
+    };
+    let chunk = |a: [Option<i64>; 2], d: &[&str], fixed: [Option<i32>; 4], map| {
+        Chunk::new(vec![
+            StructArray::new(
+                struct_type.clone(),
+                vec![Int64Array::from(a).boxed(), dictionary(d)],
+                None,
+            )
+            .boxed(),
+            ListArray::<i32>::new(
+                list_type.clone(),
+                vec![0, 2, 4].try_into().unwrap(),
+                Int32Array::from(fixed).boxed(),
+                None,
+            )
+            .boxed(),
+            MapArray::new(
+                map_type.clone(),
+                vec![0, 1, 2].try_into().unwrap(),
+                map,
+                None,
+            )
+            .boxed(),
+        ])
+    };
+    let chunks = vec![
+        chunk(
+            [Some(1), Some(2)],
+            &["x", "y"],
+            [Some(1), Some(2), Some(3), Some(4)],
+            entries(["a", "b"], [Some(1), None]),
+        ),
+        chunk(
+            [None, Some(5)],
+            &["z", "z"],
+            [Some(5), None, Some(7), Some(8)],
+            entries(["c", "d"], [Some(3), Some(4)]),
+        ),
+    ];
+    let schema = Schema::from(vec![
+        Field::new("struct", struct_type.clone(), false),
+        Field::new("fixed", list_type, false),
+        Field::new("map", map_type.clone(), false),
+    ]);
+    let data = integration_write(&schema, &chunks)?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+    // fixed-size lists are written by other implementations as lists
+    let mut fields = schema.fields;
+    fields[1].data_type =
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let statistics = fields
+        .iter()
+        .map(|field| deserialize(field, &metadata.row_groups))
+        .collect::<Result<Vec<_>>>()?;
+
+    // one statistic per row group of each leaf
+    let child = |array: &dyn Array, index: usize| {
+        array
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .values()[index]
+            .clone()
+    };
+    let min = StructArray::new(
+        struct_type,
+        vec![
+            Int64Array::from_slice([1, 5]).boxed(),
+            DictionaryArray::try_from_keys(
+                Int8Array::from_slice([0, 1]),
+                Utf8Array::<i32>::from_slice(["x", "z"]).boxed(),
+            )?
+            .boxed(),
+        ],
+        None,
+    );
+    assert_eq!(statistics[0].min_value.as_ref(), &min as &dyn Array);
+    assert_eq!(
+        child(statistics[0].max_value.as_ref(), 0).as_ref(),
+        &Int64Array::from_slice([2, 5]) as &dyn Array
+    );
+    assert_eq!(
+        child(statistics[0].null_count.as_ref(), 0).as_ref(),
+        &UInt64Array::from_slice([0, 1]) as &dyn Array
+    );
+
+    // the statistics of fixed-size lists are lists
+    let list = |values: Box<dyn Array>| {
+        ListArray::<i32>::new(
+            ListArray::<i32>::default_datatype(values.data_type().clone()),
+            vec![0, 1, 2].try_into().unwrap(),
+            values,
+            None,
+        )
+    };
+    assert_eq!(
+        statistics[1].min_value.as_ref(),
+        &list(Int32Array::from_slice([1, 5]).boxed()) as &dyn Array
+    );
+    assert_eq!(
+        statistics[1].max_value.as_ref(),
+        &list(Int32Array::from_slice([4, 8]).boxed()) as &dyn Array
+    );
+    assert_eq!(
+        statistics[1].null_count.as_ref(),
+        &list(UInt64Array::from_slice([0, 1]).boxed()) as &dyn Array
+    );
+
+    let map = |keys, values| {
+        MapArray::new(
+            map_type.clone(),
+            vec![0, 1, 2].try_into().unwrap(),
+            entries(keys, values),
+            None,
+        )
+    };
+    assert_eq!(
+        statistics[2].min_value.as_ref(),
+        &map(["a", "c"], [Some(1), Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        statistics[2].max_value.as_ref(),
+        &map(["b", "d"], [Some(1), Some(4)]) as &dyn Array
+    );
+    Ok(())
+}
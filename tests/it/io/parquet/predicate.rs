@@ -0,0 +1,223 @@
+use std::io::Cursor;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::*;
+
+use super::integration_write;
+
+// row groups of 3, 4 and 2 rows, identified by their number of rows
+fn data() -> Result<(Schema, Vec<u8>)> {
+    let dictionary = |values: &[&str]| {
+        let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(values.iter().map(Some)).unwrap();
+        DictionaryArray::from(array).boxed()
+    };
+    let chunks = vec![
+        Chunk::new(vec![
+            Int64Array::from_slice([1, 2, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "c", "e"]).boxed(),
+            dictionary(&["x", "x", "y"]),
+        ]),
+        Chunk::new(vec![
+            Int64Array::from([Some(10), None, Some(12), Some(11)]).boxed(),
+            Utf8Array::<i32>::from_slice(["f", "g", "h", "i"]).boxed(),
+            dictionary(&["y", "z", "z", "y"]),
+        ]),
+        Chunk::new(vec![
+            Int64Array::from([None, None]).boxed(),
+            Utf8Array::<i32>::from_slice(["j", "k"]).boxed(),
+            dictionary(&["z", "z"]),
+        ]),
+    ];
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("s", DataType::Utf8, false),
+        Field::new("d", chunks[0].arrays()[2].data_type().clone(), false),
+    ]);
+    let data = integration_write(&schema, &chunks)?;
+    Ok((schema, data))
+}
+
+fn prune(predicate: RowGroupPredicate) -> Result<Vec<usize>> {
+    let (schema, data) = data()?;
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let row_groups = prune_row_groups(metadata.row_groups, &schema.fields, &predicate)?;
+    Ok(row_groups.iter().map(|x| x.num_rows()).collect())
+}
+
+fn int(value: i64) -> Box<dyn Array> {
+    Int64Array::from_slice([value]).boxed()
+}
+
+fn eq(column: &str, value: Box<dyn Array>) -> RowGroupPredicate {
+    RowGroupPredicate::Eq(column.to_string(), value)
+}
+
+fn range(min: Option<i64>, max: Option<i64>) -> RowGroupPredicate {
+    RowGroupPredicate::Range {
+        column: "a".to_string(),
+        min: min.map(int),
+        max: max.map(int),
+    }
+}
+
+fn is_null() -> RowGroupPredicate {
+    RowGroupPredicate::IsNull("a".to_string())
+}
+
+#[test]
+fn eq_and_in() -> Result<()> {
+    assert_eq!(prune(eq("a", int(2)))?, vec![3]);
+    assert_eq!(prune(eq("a", int(5)))?, Vec::<usize>::new());
+    assert_eq!(
+        prune(RowGroupPredicate::In(
+            "a".to_string(),
+            Int64Array::from([None, Some(3), Some(11)]).boxed()
+        ))?,
+        vec![3, 4]
+    );
+
+    let x = Utf8Array::<i32>::from_slice(["x"]).boxed();
+    assert_eq!(prune(eq("d", x))?, vec![3]);
+    let f = Utf8Array::<i32>::from_slice(["f"]).boxed();
+    assert_eq!(prune(eq("s", f))?, vec![4]);
+    Ok(())
+}
+
+#[test]
+fn range_() -> Result<()> {
+    assert_eq!(prune(range(Some(4), Some(11)))?, vec![4]);
+    assert_eq!(prune(range(Some(3), None))?, vec![3, 4]);
+    assert_eq!(prune(range(None, Some(0)))?, Vec::<usize>::new());
+    // all non-null values of the first row group are within the range
+    assert_eq!(prune(range(Some(1), Some(3)).not())?, vec![4]);
+    Ok(())
+}
+
+#[test]
+fn null() -> Result<()> {
+    assert_eq!(prune(is_null())?, vec![4, 2]);
+    assert_eq!(prune(is_null().not())?, vec![3, 4]);
+    // comparisons with nulls are not satisfied
+    assert_eq!(prune(eq("a", int(2)).not())?, vec![3, 4]);
+    Ok(())
+}
+
+#[test]
+fn and_or() -> Result<()> {
+    assert_eq!(prune(eq("a", int(2)).or(is_null()))?, vec![3, 4, 2]);
+    assert_eq!(prune(eq("a", int(2)).and(is_null()))?, Vec::<usize>::new());
+    assert_eq!(prune(range(Some(5), None).and(is_null()))?, vec![4]);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    assert!(prune(eq("b", int(2))).is_err());
+    assert!(prune(eq("s", int(2))).is_err());
+    assert!(prune(eq("a", Int64Array::from_slice([1, 2]).boxed())).is_err());
+}
+
+#[test]
+fn nested() -> Result<()> {
+    let inner_type = DataType::Struct(vec![Field::new("x", DataType::Int64, true)]);
+    let outer_type = DataType::Struct(vec![
+        Field::new("inner", inner_type.clone(), true),
+        Field::new("y.z", DataType::Int64, true),
+    ]);
+    let chunk = |x: [Option<i64>; 2], y: [i64; 2]| {
+        let inner = StructArray::new(inner_type.clone(), vec![Int64Array::from(x).boxed()], None);
+        Chunk::new(vec![StructArray::new(
+            outer_type.clone(),
+            vec![inner.boxed(), Int64Array::from_slice(y).boxed()],
+            None,
+        )
+        .boxed()])
+    };
+    let schema = Schema::from(vec![Field::new("a", outer_type.clone(), false)]);
+    let chunks = vec![
+        chunk([Some(1), Some(2)], [1, 1]),
+        chunk([None, None], [2, 3]),
+    ];
+    let data = integration_write(&schema, &chunks)?;
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let prune = |predicate| {
+        prune_row_groups(metadata.row_groups.clone(), &schema.fields, &predicate).map(|x| x.len())
+    };
+
+    let x = "a.inner.x".to_string();
+    assert_eq!(prune(RowGroupPredicate::Eq(x.clone(), int(2)))?, 1);
+    assert_eq!(prune(RowGroupPredicate::IsNull(x))?, 1);
+    // the name of a field may contain `.`
+    let y = "a.y.z".to_string();
+    assert_eq!(prune(RowGroupPredicate::Eq(y.clone(), int(3)))?, 1);
+    assert_eq!(prune(RowGroupPredicate::Eq(y, int(4)))?, 0);
+
+    assert!(prune(RowGroupPredicate::IsNull("a.x".to_string())).is_err());
+    // nested statistics are not compared
+    assert!(prune(RowGroupPredicate::Eq("a.inner".to_string(), int(2))).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[test]
+fn bloom_filter() -> Result<()> {
+    use arrow2::io::parquet::write::*;
+
+    let (schema, _) = data()?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        ..Default::default()
+    };
+    let bloom_options = BloomFilterOptions { fpp: 0.01, ndv: 8 };
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.set_bloom_filters(vec![Some(bloom_options); 3])?;
+    let chunk = Chunk::new(vec![
+        Int64Array::from_slice([1, 3]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "e"]).boxed(),
+        DictionaryArray::<i32>::try_from_keys(
+            Int32Array::from_slice([0, 0]),
+            Utf8Array::<i32>::from_slice(["x"]).boxed(),
+        )?
+        .boxed(),
+    ]);
+    let encodings = vec![
+        vec![Encoding::Plain],
+        vec![Encoding::Plain],
+        vec![Encoding::RleDictionary],
+    ];
+    let row_groups =
+        RowGroupIterator::try_new(std::iter::once(Ok(chunk)), &schema, options, encodings)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let mut prune = |predicate| {
+        prune_row_groups_with_bloom_filters(
+            &mut reader,
+            metadata.row_groups.clone(),
+            &schema.fields,
+            &predicate,
+        )
+        .map(|x| x.len())
+    };
+
+    assert_eq!(prune(eq("a", int(3)))?, 1);
+    // within the statistics' range but not in the bloom filter
+    assert_eq!(prune(eq("a", int(2)))?, 0);
+    let c = Utf8Array::<i32>::from_slice(["c"]).boxed();
+    assert_eq!(prune(eq("s", c))?, 0);
+    let x = Utf8Array::<i32>::from_slice(["x"]).boxed();
+    assert_eq!(prune(eq("d", x))?, 1);
+    Ok(())
+}
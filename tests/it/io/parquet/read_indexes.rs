@@ -28,6 +28,7 @@ fn pages(arrays: &[&dyn Array], encoding: Encoding) -> Result<(Vec<Page>, Vec<Pa
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let pages1 = [array11, array12, array13]
@@ -78,6 +79,7 @@ fn read_with_indexes(
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let to_compressed = |pages: Vec<Page>| {
@@ -291,3 +293,143 @@ fn indexed_dict() -> Result<()> {
 
     read_with_indexes(pages(&[&array], Encoding::RleDictionary)?, expected)
 }
+
+type LateMaterializationData = (Schema, Chunk<Box<dyn Array>>, Vec<u8>);
+
+fn late_materialization_data() -> Result<LateMaterializationData> {
+    let a = Int64Array::from_values(0..100);
+    let b = Utf8Array::<i32>::from_iter((0..100).map(|x| (x % 3 != 0).then(|| x.to_string())));
+    let mut c = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    c.try_extend((0..100).map(|x| Some(vec![Some(x), None])))?;
+    let c: ListArray<i32> = c.into();
+    let chunk = Chunk::new(vec![a.boxed(), b.boxed(), c.boxed()]);
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", chunk.arrays()[2].data_type().clone(), false),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: Some(128),
+        ..Default::default()
+    };
+    // two row groups of 50 rows
+    let chunks = [0, 50].map(|offset| {
+        Ok(Chunk::new(
+            chunk
+                .arrays()
+                .iter()
+                .map(|x| x.sliced(offset, 50))
+                .collect(),
+        ))
+    });
+    let row_groups = RowGroupIterator::try_new(
+        chunks.into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]; 3],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok((schema, chunk, writer.into_inner().into_inner()))
+}
+
+// selects `a` in 10..20 or `a >= 95`
+fn late_materialization_predicate(arrays: &[Box<dyn Array>]) -> Result<BooleanArray> {
+    let a = arrays[0].as_any().downcast_ref::<Int64Array>().unwrap();
+    Ok(BooleanArray::from_trusted_len_values_iter(
+        a.values_iter().map(|x| (10..20).contains(x) || *x >= 95),
+    ))
+}
+
+#[test]
+fn late_materialization() -> Result<()> {
+    let (schema, chunk, data) = late_materialization_data()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    // pages without selected rows are skipped
+    let pages = indexes::read_selected_pages(
+        &mut reader,
+        &metadata.row_groups[0],
+        &schema.fields[..1],
+        &[indexes::Interval::new(10, 10)],
+    )?;
+    assert!(pages[0][0].len() > 1);
+    assert!(pages[0][0].iter().any(|page| page.selected_rows.is_empty()));
+
+    let reader = FilteredFileReader::new(
+        reader,
+        metadata.row_groups,
+        schema,
+        vec![0],
+        late_materialization_predicate,
+    );
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 2);
+    for (result, (offset, length)) in chunks.iter().zip([(10, 10), (95, 5)]) {
+        for (result, expected) in result.arrays().iter().zip(chunk.arrays()) {
+            assert_eq!(result.as_ref(), expected.sliced(offset, length).as_ref());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn late_materialization_predicate_on_many_fields() -> Result<()> {
+    let (schema, chunk, data) = late_materialization_data()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    // selects the rows where `b` is null and `a` is even
+    let result = read_row_group_filtered(
+        &mut reader,
+        &metadata.row_groups[1],
+        &schema.fields,
+        &[1, 0],
+        |arrays| {
+            let a = arrays[1].as_any().downcast_ref::<Int64Array>().unwrap();
+            Ok(BooleanArray::from_trusted_len_values_iter(
+                a.values_iter()
+                    .enumerate()
+                    .map(|(i, x)| arrays[0].is_null(i) && x % 2 == 0),
+            ))
+        },
+    )?;
+    assert_eq!(
+        result.arrays()[0].as_ref(),
+        &Int64Array::from_values((50..100).filter(|x| x % 6 == 0)) as &dyn Array
+    );
+    assert_eq!(result.arrays()[1].null_count(), result.len());
+    assert_eq!(result.arrays()[2].len(), result.len());
+
+    // no selected row
+    let result = read_row_group_filtered(
+        &mut reader,
+        &metadata.row_groups[0],
+        &schema.fields,
+        &[0],
+        |arrays| Ok(BooleanArray::new_null(DataType::Boolean, arrays[0].len())),
+    )?;
+    assert!(result.is_empty());
+    assert_eq!(
+        result.arrays()[2].data_type(),
+        chunk.arrays()[2].data_type()
+    );
+
+    let result = read_row_group_filtered(
+        &mut reader,
+        &metadata.row_groups[0],
+        &schema.fields,
+        &[0],
+        |_| Ok(BooleanArray::from_slice([true])),
+    );
+    assert!(result.is_err());
+    Ok(())
+}
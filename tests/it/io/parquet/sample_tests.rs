@@ -80,6 +80,7 @@ fn round_trip_sample(
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let encodings: Vec<_> = schema
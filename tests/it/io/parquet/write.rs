@@ -53,6 +53,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let iter = vec![Chunk::try_new(vec![array.clone()])];
@@ -665,6 +666,628 @@ fn i32_optional_v2_dict_compressed() -> Result<()> {
     )
 }
 
+#[test]
+fn int64_optional_v1_dict() -> Result<()> {
+    round_trip(
+        "int64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::RleDictionary],
+    )
+}
+
+#[test]
+fn int64_required_v2_dict() -> Result<()> {
+    round_trip(
+        "int64",
+        "required",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::RleDictionary],
+    )
+}
+
+#[test]
+fn float64_optional_v1_dict() -> Result<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::RleDictionary],
+    )
+}
+
+#[test]
+fn utf8_optional_v2_dict() -> Result<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::RleDictionary],
+    )
+}
+
+#[test]
+fn list_utf8_optional_v1_dict() -> Result<()> {
+    round_trip(
+        "list_utf8",
+        "nested",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::RleDictionary],
+    )
+}
+
+fn write_dictionary_fallback(limit: usize, fallback: Encoding) -> Result<Vec<u8>> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bb"), Some("a")]);
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        dictionary_pagesize_limit: Some(limit),
+        dictionary_fallback: fallback,
+        ..Default::default()
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.boxed()])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::RleDictionary]],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(writer.into_inner().into_inner())
+}
+
+#[test]
+fn dictionary_fallback() -> Result<()> {
+    let expected = Utf8Array::<i32>::from([Some("a"), None, Some("bb"), Some("a")]);
+
+    // "a" and "bb" require 4 + 1 and 4 + 2 bytes
+    for (limit, is_dictionary) in [(11, true), (10, false)] {
+        let data = write_dictionary_fallback(limit, Encoding::DeltaLengthByteArray)?;
+        let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+        let column = &metadata.row_groups[0].columns()[0];
+        let encodings = column
+            .column_encoding()
+            .iter()
+            .map(|encoding| Encoding::try_from(*encoding).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(encodings.contains(&Encoding::RleDictionary), is_dictionary);
+        assert_eq!(
+            encodings.contains(&Encoding::DeltaLengthByteArray),
+            !is_dictionary
+        );
+
+        let (result, _) = read_column(&mut Cursor::new(data), "a1")?;
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+    Ok(())
+}
+
+#[test]
+fn dictionary_fallback_is_not_dictionary() {
+    for fallback in [Encoding::RleDictionary, Encoding::PlainDictionary] {
+        assert!(write_dictionary_fallback(10, fallback).is_err());
+    }
+}
+
+#[test]
+fn float64_optional_v1_byte_stream_split() -> Result<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn utf8_optional_v2_delta_byte_array() -> Result<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn utf8_required_v1_delta_byte_array() -> Result<()> {
+    round_trip(
+        "string",
+        "required",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn list_utf8_optional_v2_delta_byte_array() -> Result<()> {
+    round_trip(
+        "list_utf8",
+        "nested",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn list_large_binary_optional_v1_delta_byte_array() -> Result<()> {
+    round_trip(
+        "list_large_binary",
+        "nested",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+// writes `array` with `encoding`, asserts that it is read back and returns the column's encodings
+fn round_trip_encoding(array: Box<dyn Array>, encoding: Encoding) -> Result<Vec<Encoding>> {
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        ..Default::default()
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![vec![encoding]])?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    let encodings = metadata.row_groups[0].columns()[0]
+        .column_encoding()
+        .iter()
+        .map(|encoding| Encoding::try_from(*encoding).unwrap())
+        .collect::<Vec<_>>();
+
+    let (result, _) = read_column(&mut Cursor::new(data), "a1")?;
+    assert_eq!(result.as_ref(), array.as_ref());
+    Ok(encodings)
+}
+
+#[test]
+fn byte_stream_split() -> Result<()> {
+    assert!(can_encode(&DataType::Float32, Encoding::ByteStreamSplit));
+    assert!(!can_encode(&DataType::Utf8, Encoding::ByteStreamSplit));
+
+    let array = Float32Array::from([Some(1.5), None, Some(-0.25), Some(f32::MAX)]);
+    let encodings = round_trip_encoding(array.boxed(), Encoding::ByteStreamSplit)?;
+    assert!(encodings.contains(&Encoding::ByteStreamSplit));
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<f64>>::new();
+    array.try_extend(vec![
+        Some(vec![Some(1.0), None, Some(2.5)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(-3.0)]),
+    ])?;
+    let array: ListArray<i32> = array.into();
+    let encodings = round_trip_encoding(array.boxed(), Encoding::ByteStreamSplit)?;
+    assert!(encodings.contains(&Encoding::ByteStreamSplit));
+
+    // nested non-floats are not encoded by it
+    let array = pyarrow_nested_nullable("list_int64");
+    let type_ = to_parquet_type(&Field::new("a1", array.data_type().clone(), true))?;
+    let result = array_to_columns(
+        array,
+        type_,
+        Default::default(),
+        &[Encoding::ByteStreamSplit],
+    )
+    .and_then(|columns| columns.into_iter().flatten().collect::<Result<Vec<_>>>());
+    assert!(matches!(
+        result,
+        Err(arrow2::error::Error::InvalidArgumentError(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn delta_byte_array() -> Result<()> {
+    assert!(can_encode(&DataType::LargeBinary, Encoding::DeltaByteArray));
+    assert!(!can_encode(&DataType::Int64, Encoding::DeltaByteArray));
+
+    let array = Utf8Array::<i32>::from([
+        Some("key/a/1"),
+        Some("key/a/10"),
+        None,
+        Some("key/b"),
+        Some(""),
+        Some("key/b/2"),
+    ]);
+    let encodings = round_trip_encoding(array.boxed(), Encoding::DeltaByteArray)?;
+    assert!(encodings.contains(&Encoding::DeltaByteArray));
+
+    let mut array = MutableListArray::<i32, MutableBinaryArray<i64>>::new();
+    array.try_extend(vec![
+        Some(vec![Some(b"prefix1".as_ref()), None, Some(b"prefix12")]),
+        None,
+        Some(vec![Some(b"prefix2")]),
+    ])?;
+    let array: ListArray<i32> = array.into();
+    let encodings = round_trip_encoding(array.boxed(), Encoding::DeltaByteArray)?;
+    assert!(encodings.contains(&Encoding::DeltaByteArray));
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[test]
+fn bloom_filter() -> Result<()> {
+    use arrow2::io::parquet::bloom_filter::{hash_byte, hash_native, is_in_set, read};
+
+    let ints = Int64Array::from([Some(1), None, Some(3), Some(-4)]);
+    let strings = Utf8Array::<i32>::from([Some("a"), Some("bb"), None, Some("ccc")]);
+    let list = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(DataType::Int64),
+        vec![0, 2, 2, 3, 4].try_into()?,
+        Int64Array::from([Some(10), None, Some(30), Some(40)]).boxed(),
+        Some([true, false, true, true].into()),
+    );
+    let schema = Schema::from(vec![
+        Field::new("a1", ints.data_type().clone(), true),
+        Field::new("a2", strings.data_type().clone(), true),
+        Field::new("a3", DataType::Boolean, true),
+        Field::new("a4", list.data_type().clone(), true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Zstd(None),
+        version: Version::V2,
+        data_pagesize_limit: None,
+        max_rows_per_row_group: Some(2),
+        ..Default::default()
+    };
+    let bloom_options = BloomFilterOptions {
+        fpp: 0.01,
+        ndv: 100,
+    };
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    assert!(writer.set_bloom_filters(vec![Some(bloom_options)]).is_err());
+    // booleans have no bloom filters
+    assert!(writer
+        .set_bloom_filters(vec![Some(bloom_options); 4])
+        .is_err());
+    let bloom = Some(bloom_options);
+    writer.set_bloom_filters(vec![bloom, bloom, None, bloom])?;
+    let chunk = Chunk::new(vec![
+        ints.clone().boxed(),
+        strings.clone().boxed(),
+        BooleanArray::from([Some(true), None, Some(false), None]).boxed(),
+        list.boxed(),
+    ]);
+    let row_groups = RowGroupIterator::try_new(
+        std::iter::once(Ok(chunk.clone())),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]; 4],
+    )?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    let mut reader = Cursor::new(&data);
+    let mut bitset = vec![];
+    let list_values = [vec![10i64], vec![30, 40]];
+    for ((group, rows), list_values) in metadata
+        .row_groups
+        .iter()
+        .zip([0..2, 2..4])
+        .zip(list_values)
+    {
+        let columns = group.columns();
+
+        read(&columns[0], &mut reader, &mut bitset)?;
+        assert_eq!(bitset.len(), bloom_options.num_bytes());
+        for value in ints.clone().sliced(rows.start, 2).iter().flatten() {
+            assert!(is_in_set(&bitset, hash_native(*value)));
+        }
+        assert!(!is_in_set(&bitset, hash_native(1234567i64)));
+
+        read(&columns[1], &mut reader, &mut bitset)?;
+        for value in strings.clone().sliced(rows.start, 2).iter().flatten() {
+            assert!(is_in_set(&bitset, hash_byte(value)));
+        }
+        assert!(!is_in_set(&bitset, hash_byte("not a value")));
+
+        read(&columns[2], &mut reader, &mut bitset)?;
+        assert!(bitset.is_empty());
+
+        read(&columns[3], &mut reader, &mut bitset)?;
+        for value in list_values {
+            assert!(is_in_set(&bitset, hash_native(value)));
+        }
+        assert!(!is_in_set(&bitset, hash_native(1234567i64)));
+    }
+    // the values of a row group are not in the bloom filter of the other
+    read(
+        &metadata.row_groups[1].columns()[0],
+        &mut reader,
+        &mut bitset,
+    )?;
+    assert!(!is_in_set(&bitset, hash_native(1i64)));
+
+    // the file remains readable
+    let (_, read) = integration_read(&data, None)?;
+    let slice = |offset| Chunk::new(chunk.iter().map(|x| x.sliced(offset, 2)).collect());
+    assert_eq!(read, vec![slice(0), slice(2)]);
+    Ok(())
+}
+
+fn write_sized(
+    chunks: &[Chunk<Box<dyn Array>>],
+    options: WriteOptions,
+    encodings: Vec<Vec<Encoding>>,
+) -> Result<Vec<u8>> {
+    let schema = Schema::from(
+        chunks[0]
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, x)| Field::new(format!("c{i}"), x.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let row_groups =
+        RowGroupIterator::try_new(chunks.iter().cloned().map(Ok), &schema, options, encodings)?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(writer.into_inner().into_inner())
+}
+
+fn sized_options() -> WriteOptions {
+    WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn row_group_size() -> Result<()> {
+    let chunk = |range: std::ops::Range<i64>| {
+        Chunk::new(vec![
+            Int64Array::from_iter(range.clone().map(|x| (x % 3 != 0).then_some(x))).boxed(),
+            Utf8Array::<i32>::from_iter_values(range.map(|x| x.to_string())).boxed(),
+        ])
+    };
+    let chunks = vec![chunk(0..10), chunk(10..35), chunk(35..35), chunk(35..40)];
+    let encodings = vec![vec![Encoding::Plain]; 2];
+    let num_rows = |data: &[u8]| -> Result<Vec<usize>> {
+        let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+        Ok(metadata.row_groups.iter().map(|x| x.num_rows()).collect())
+    };
+
+    // each chunk is a row group
+    let data = write_sized(&chunks, sized_options(), encodings.clone())?;
+    assert_eq!(num_rows(&data)?, vec![10, 25, 0, 5]);
+
+    let mut options = sized_options();
+    options.max_rows_per_row_group = Some(16);
+    let data = write_sized(&chunks, options, encodings.clone())?;
+    assert_eq!(num_rows(&data)?, vec![16, 16, 8]);
+    let (_, read) = integration_read(&data, None)?;
+    let expected = vec![chunk(0..16), chunk(16..32), chunk(32..40)];
+    assert_eq!(read, expected);
+
+    // each row is estimated at 8 + 1 / 8 bytes of the ints and about 6 bytes of the strings
+    let mut options = sized_options();
+    options.max_bytes_per_row_group = Some(14 * 10);
+    let data = write_sized(&chunks, options, encodings)?;
+    let rows = num_rows(&data)?;
+    assert!(rows.len() > 1);
+    assert!(rows.iter().all(|x| *x <= 12));
+    assert_eq!(rows.iter().sum::<usize>(), 40);
+    let (_, read) = integration_read(&data, None)?;
+    let ints = read
+        .iter()
+        .flat_map(|x| {
+            let array = x.arrays()[0].as_any().downcast_ref::<Int64Array>().unwrap();
+            array.iter().map(|x| x.copied()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ints,
+        (0..40)
+            .map(|x| (x % 3 != 0).then_some(x))
+            .collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn row_group_size_zero() {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let chunk = Chunk::new(vec![Int64Array::from_slice([1, 2]).boxed()]);
+
+    let mut rows = sized_options();
+    rows.max_rows_per_row_group = Some(0);
+    let mut bytes = sized_options();
+    bytes.max_bytes_per_row_group = Some(0);
+    for options in [rows, bytes] {
+        let iter = vec![Ok(chunk.clone())].into_iter();
+        let encodings = vec![vec![Encoding::Plain]];
+        assert!(RowGroupIterator::try_new(iter, &schema, options, encodings).is_err());
+    }
+}
+
+#[test]
+fn max_rows_per_page() -> Result<()> {
+    let keys = Int32Array::from_iter((0..100).map(|x| (x % 7 != 0).then_some(x % 4)));
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    let chunk = Chunk::new(vec![
+        Int64Array::from_iter((0..100).map(|x| (x % 5 != 0).then_some(x))).boxed(),
+        DictionaryArray::try_from_keys(keys, values.boxed())?.boxed(),
+        ListArray::<i32>::new(
+            ListArray::<i32>::default_datatype(DataType::Int64),
+            (0..=100).collect::<Vec<_>>().try_into()?,
+            Int64Array::from_iter((0..100).map(Some)).boxed(),
+            None,
+        )
+        .boxed(),
+    ]);
+    let mut options = sized_options();
+    options.max_rows_per_page = Some(30);
+    let encodings = vec![
+        vec![Encoding::Plain],
+        vec![Encoding::RleDictionary],
+        vec![Encoding::Plain],
+    ];
+    let data = write_sized(&[chunk.clone()], options, encodings)?;
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    let locations =
+        p_read::read_pages_locations(&mut Cursor::new(&data), metadata.row_groups[0].columns())?;
+    for column in locations {
+        let first_rows = column.iter().map(|x| x.first_row_index).collect::<Vec<_>>();
+        assert_eq!(first_rows, vec![0, 30, 60, 90]);
+    }
+    let (_, read) = integration_read(&data, None)?;
+    assert_eq!(read, vec![chunk]);
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_parallel")]
+#[test]
+fn parallel() -> Result<()> {
+    use arrow2::error::Error;
+
+    let chunk = |offset: i64| {
+        let ints = Int64Array::from_iter((offset..offset + 100).map(|x| (x % 7 != 0).then_some(x)));
+        let strings = Utf8Array::<i32>::from_iter_values((0..100).map(|x| format!("{x}")));
+        let list = ListArray::<i32>::new(
+            ListArray::<i32>::default_datatype(DataType::Int64),
+            (0..=100)
+                .map(|x| x * 2)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            Int64Array::from_iter((0..200).map(|x| (x % 3 != 0).then_some(x))).boxed(),
+            None,
+        );
+        Chunk::new(vec![ints.boxed(), strings.boxed(), list.boxed()])
+    };
+    let chunks = vec![chunk(0), chunk(100), chunk(200)];
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int64, true),
+        Field::new("a2", DataType::Utf8, false),
+        Field::new("a3", chunks[0].arrays()[2].data_type().clone(), false),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Zstd(None),
+        version: Version::V2,
+        data_pagesize_limit: Some(256),
+        ..Default::default()
+    };
+    let encodings = vec![
+        vec![Encoding::DeltaBinaryPacked],
+        vec![Encoding::RleDictionary],
+        vec![Encoding::Plain],
+    ];
+
+    let write = |row_groups: Vec<RowGroupIter<'static, Error>>| -> Result<Vec<u8>> {
+        let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+        for group in row_groups {
+            writer.write(group)?;
+        }
+        writer.end(None)?;
+        Ok(writer.into_inner().into_inner())
+    };
+    let expected = write(
+        RowGroupIterator::try_new(
+            chunks.clone().into_iter().map(Ok),
+            &schema,
+            options,
+            encodings.clone(),
+        )?
+        .collect::<Result<_>>()?,
+    )?;
+    let result = write(
+        ParallelRowGroupIterator::try_new(
+            chunks.clone().into_iter().map(Ok),
+            &schema,
+            options,
+            encodings.clone(),
+        )?
+        .collect::<Result<_>>()?,
+    )?;
+    // the output is deterministic
+    assert_eq!(result, expected);
+
+    let fields = to_parquet_schema(&schema)?.fields().to_vec();
+    let row_group = par_row_group_iter(chunks[0].clone(), encodings.clone(), fields, options)?;
+    let result = write(vec![row_group])?;
+    let (_, read) = integration_read(&result, None)?;
+    assert_eq!(read, vec![chunks[0].clone()]);
+
+    // errors of the chunks are returned in order
+    let mut iter = ParallelRowGroupIterator::try_new(
+        vec![
+            Ok(chunks[0].clone()),
+            Err(Error::InvalidArgumentError("error".to_string())),
+            Ok(Chunk::new(vec![chunks[0].arrays()[0].clone()])),
+        ]
+        .into_iter(),
+        &schema,
+        options,
+        encodings,
+    )?;
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+
+    // panics of the encoding are returned as errors
+    let arrays = chunks[0].arrays();
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int64, 2);
+    let list = FixedSizeListArray::new_null(data_type, 100).boxed();
+    let chunk = Chunk::new(vec![list, arrays[1].clone(), arrays[2].clone()]);
+    let mut iter = ParallelRowGroupIterator::try_new(
+        std::iter::once(Ok(chunk)),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]; 3],
+    )?;
+    assert!(iter.next().unwrap().is_err());
+    Ok(())
+}
+
 // Decimal Testing
 #[test]
 fn decimal_9_optional_v1() -> Result<()> {
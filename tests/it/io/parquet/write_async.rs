@@ -5,11 +5,14 @@ use arrow2::{
     datatypes::{DataType, Field, Schema},
     error::Result,
     io::parquet::{
-        read::{infer_schema, read_columns_many_async, read_metadata_async, RowGroupDeserializer},
+        read::{
+            indexes, infer_schema, read_columns_many_async, read_metadata, read_metadata_async,
+            FileReader, FileStream, RowGroupDeserializer,
+        },
         write::{CompressionOptions, Encoding, Version, WriteOptions},
     },
 };
-use futures::{future::BoxFuture, io::Cursor, SinkExt};
+use futures::{future::BoxFuture, io::Cursor, SinkExt, TryStreamExt};
 
 use super::FileSink;
 
@@ -32,6 +35,7 @@ async fn test_parquet_async_roundtrip() {
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        ..Default::default()
     };
 
     let mut buffer = Cursor::new(Vec::new());
@@ -74,3 +78,205 @@ async fn test_parquet_async_roundtrip() {
         assert_eq!(data[i], out[i]);
     }
 }
+
+async fn write_row_groups(options: WriteOptions) -> (Schema, Vec<u8>) {
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int32, true),
+        Field::new("a2", DataType::Float32, true),
+    ]);
+    let encoding = vec![vec![Encoding::Plain], vec![Encoding::Plain]];
+
+    let mut buffer = Cursor::new(Vec::new());
+    let mut sink = FileSink::try_new(&mut buffer, schema.clone(), encoding, options).unwrap();
+    for i in 0..5 {
+        let a1 = Int32Array::from(&[Some(i), None, Some(i + 1)]);
+        let a2 = Float32Array::from(&[None, Some(i as f32), None]);
+        sink.feed(Chunk::new(vec![a1.boxed(), a2.boxed()]))
+            .await
+            .unwrap();
+    }
+    sink.close().await.unwrap();
+    drop(sink);
+    (schema, buffer.into_inner())
+}
+
+fn stream_options(max_rows_per_page: Option<usize>) -> WriteOptions {
+    WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        max_rows_per_page,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn file_stream() -> Result<()> {
+    let (schema, data) = write_row_groups(stream_options(None)).await;
+    let mut buffer = Cursor::new(data.clone());
+    let metadata = read_metadata_async(&mut buffer).await?;
+    assert_eq!(metadata.row_groups.len(), 5);
+
+    // projection, chunk size and limit
+    let schema = schema.filter(|_, field| field.name == "a2");
+    let factory = || Box::pin(futures::future::ready(Ok(buffer.clone()))) as BoxFuture<_>;
+    let stream = FileStream::new(
+        factory,
+        metadata.row_groups.clone(),
+        schema.clone(),
+        Some(2),
+        Some(7),
+        None,
+    );
+    assert_eq!(stream.schema(), &schema);
+    let chunks = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = FileReader::new(
+        std::io::Cursor::new(data),
+        metadata.row_groups,
+        schema,
+        Some(2),
+        Some(7),
+        None,
+    )
+    .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(chunks, expected);
+    assert_eq!(chunks.iter().map(|x| x.len()).sum::<usize>(), 7);
+    assert!(chunks.iter().all(|x| x.arrays().len() == 1 && x.len() <= 2));
+    Ok(())
+}
+
+#[test]
+fn row_group_limits_are_rejected() {
+    let schema = Schema::from(vec![Field::new("a1", DataType::Int32, true)]);
+    let encoding = vec![vec![Encoding::Plain]];
+    for options in [
+        WriteOptions {
+            max_rows_per_row_group: Some(2),
+            ..stream_options(None)
+        },
+        WriteOptions {
+            max_bytes_per_row_group: Some(1024),
+            ..stream_options(None)
+        },
+    ] {
+        let sink = FileSink::try_new(vec![], schema.clone(), encoding.clone(), options);
+        assert!(sink.is_err());
+    }
+}
+
+#[tokio::test]
+async fn file_stream_with_page_indexes() -> Result<()> {
+    // a page per row
+    let (schema, data) = write_row_groups(stream_options(Some(1))).await;
+    let mut reader = std::io::Cursor::new(data.clone());
+    let metadata = read_metadata(&mut reader)?;
+
+    // select the second row of every row group
+    let pages = metadata
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            indexes::read_filtered_pages(&mut reader, row_group, &schema.fields, |_, intervals| {
+                let first_field_column = &intervals[0][0];
+                assert_eq!(first_field_column.len(), 3);
+                vec![first_field_column[1]]
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let buffer = Cursor::new(data);
+    let factory = || Box::pin(futures::future::ready(Ok(buffer.clone()))) as BoxFuture<_>;
+    let stream = FileStream::new(
+        factory,
+        metadata.row_groups.clone(),
+        schema.clone(),
+        None,
+        None,
+        Some(pages.clone()),
+    );
+    let chunks = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = FileReader::new(reader, metadata.row_groups, schema, None, None, Some(pages))
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(chunks, expected);
+    let a2 = chunks
+        .iter()
+        .map(|chunk| chunk.arrays()[1].clone())
+        .collect::<Vec<_>>();
+    let expected = (0..5)
+        .map(|i| Float32Array::from(&[Some(i as f32)]).boxed())
+        .collect::<Vec<_>>();
+    assert_eq!(a2, expected);
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[tokio::test]
+async fn bloom_filter() -> Result<()> {
+    use arrow2::io::parquet::{
+        bloom_filter::{hash_native, is_in_set, read},
+        write::BloomFilterOptions,
+    };
+
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::Int32, true),
+        Field::new("a2", DataType::Float32, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        ..Default::default()
+    };
+    let bloom_options = BloomFilterOptions { fpp: 0.01, ndv: 10 };
+
+    let mut buffer = Cursor::new(Vec::new());
+    let encodings = vec![vec![Encoding::Plain]; 2];
+    let mut sink = FileSink::try_new(&mut buffer, schema.clone(), encodings, options)?;
+    assert!(sink.set_bloom_filters(vec![Some(bloom_options)]).is_err());
+    sink.set_bloom_filters(vec![Some(bloom_options), None])?;
+    let chunks = (0..2)
+        .map(|i| {
+            let a1 = Int32Array::from(&[Some(i), None]);
+            let a2 = Float32Array::from(&[None, Some(i as f32)]);
+            Chunk::new(vec![a1.boxed(), a2.boxed()])
+        })
+        .collect::<Vec<_>>();
+    for chunk in &chunks {
+        sink.feed(chunk.clone()).await?;
+    }
+    sink.close().await?;
+    drop(sink);
+
+    let data = buffer.into_inner();
+    let metadata = read_metadata(&mut std::io::Cursor::new(&data))?;
+    let mut reader = std::io::Cursor::new(&data);
+    let mut bitset = vec![];
+    for (i, group) in metadata.row_groups.iter().enumerate() {
+        read(&group.columns()[0], &mut reader, &mut bitset)?;
+        assert_eq!(bitset.len(), bloom_options.num_bytes());
+        assert!(is_in_set(&bitset, hash_native(i as i32)));
+        assert!(!is_in_set(&bitset, hash_native(1234567i32)));
+
+        read(&group.columns()[1], &mut reader, &mut bitset)?;
+        assert!(bitset.is_empty());
+    }
+
+    // the file remains readable
+    let read = FileReader::new(
+        std::io::Cursor::new(data),
+        metadata.row_groups,
+        schema,
+        None,
+        None,
+        None,
+    )
+    .collect::<Result<Vec<_>>>()?;
+    assert_eq!(read, chunks);
+    Ok(())
+}